@@ -0,0 +1,47 @@
+//! Day 1 part 1 on a generated input of several megabytes: the byte scanner against
+//! reading each line a character at a time. Run with `cargo bench --bench day1`.
+use std::hint::black_box;
+use std::time::Instant;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use aoc::bench::{Micros, Stats};
+use aoc::days::day1::{calibration_sum, calibration_value, Day1};
+use aoc::solution::Solution;
+
+const LINES: usize = 250_000;
+const ITERATIONS: usize = 20;
+
+type Scan = fn(&str) -> Option<u32>;
+
+fn by_line(text: &str) -> Option<u32> {
+    text.lines().map(calibration_value).sum()
+}
+
+fn by_bytes(text: &str) -> Option<u32> {
+    calibration_sum(text.as_bytes()).ok()
+}
+
+fn main() {
+    let text = Day1::generate(&mut StdRng::seed_from_u64(0), LINES);
+    let mb = text.len() as f64 / 1e6;
+    assert_eq!(by_line(&text), by_bytes(&text), "the two ways disagree");
+    println!("{LINES} lines, {mb:.1} MB, {ITERATIONS} iterations");
+    println!("{:<8}  {:>9}  {:>9}  {:>9}  {:>9}", "scan", "mean", "median", "stddev", "MB/s");
+    let scans: [(&str, Scan); 2] = [("lines", by_line), ("bytes", by_bytes)];
+    let mut medians = vec![];
+    for (name, scan) in scans {
+        let samples: Vec<_> = (0..ITERATIONS)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(scan(black_box(&text)));
+                start.elapsed()
+            })
+            .collect();
+        let stats = Stats::new(&samples);
+        println!("{name:<8}  {:>9}  {:>9}  {:>9}  {:>9.0}", Micros(stats.mean_us), Micros(stats.median_us),
+                 Micros(stats.stddev_us), mb / (stats.median_us / 1e6));
+        medians.push(stats.median_us);
+    }
+    println!("bytes is {:.1}x as fast", medians[0] / medians[1]);
+}
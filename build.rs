@@ -0,0 +1,52 @@
+//! Generate a golden test for every part listed in `expected/`, see `tests/golden.rs`,
+//! and the table of sample inputs built into the binary, see `src/input.rs`.
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=expected");
+    let mut paths: Vec<_> = fs::read_dir("expected")
+        .expect("cannot read expected/")
+        .map(|e| e.expect("cannot read expected/").path())
+        .collect();
+    paths.sort();
+    let mut tests = String::new();
+    for path in paths {
+        let name = path.file_name().and_then(|n| n.to_str()).expect("non UTF-8 file name");
+        let Some(stem) = name.strip_suffix(".txt") else { continue };
+        let ident: String = stem.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+        let text = fs::read_to_string(&path).expect("cannot read expected output");
+        let mut seen = Vec::new();
+        for (part, _) in text.lines().filter_map(|l| l.trim().strip_prefix("part ")?.split_once(':')) {
+            assert!(!seen.contains(&part), "{name}: part {part} is listed twice");
+            seen.push(part);
+            writeln!(tests, "#[test]\nfn {ident}_part{part}() {{\n    golden({name:?}, {part});\n}}\n").unwrap();
+        }
+    }
+    let out = env::var("OUT_DIR").unwrap();
+    let out = Path::new(&out);
+    fs::write(out.join("golden.rs"), tests).unwrap();
+    fs::write(out.join("samples.rs"), samples()).unwrap();
+}
+
+/// `SAMPLES`, every `dayN-sample*.txt` at the top of the repo with its text.
+fn samples() -> String {
+    let root = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let mut names: Vec<String> = fs::read_dir(&root)
+        .expect("cannot read the repo")
+        .filter_map(|e| e.expect("cannot read the repo").file_name().into_string().ok())
+        .filter(|name| name.starts_with("day") && name.contains("-sample") && name.ends_with(".txt"))
+        .collect();
+    names.sort();
+    let mut table = String::from("const SAMPLES: &[(&str, &str)] = &[\n");
+    for name in names {
+        let path = Path::new(&root).join(&name);
+        println!("cargo:rerun-if-changed={}", path.display());
+        writeln!(table, "    ({name:?}, include_str!({:?})),", path.display().to_string()).unwrap();
+    }
+    table.push_str("];\n");
+    table
+}
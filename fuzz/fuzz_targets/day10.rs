@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use aoc::days::day10::Day10;
+use aoc::solution::Solution;
+
+// Any input may be rejected, but parsing and solving must not panic.
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = Day10::parse(input) {
+        let _ = Day10::part1(&parsed);
+        let _ = Day10::part2(&parsed);
+    }
+});
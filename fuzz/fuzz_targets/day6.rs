@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use aoc::days::day6::Day6;
+use aoc::solution::Solution;
+
+// Any input may be rejected, but parsing and solving must not panic.
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = Day6::parse(input) {
+        let _ = Day6::part1(&parsed);
+        let _ = Day6::part2(&parsed);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use aoc::days::day9::Day9;
+use aoc::solution::Solution;
+
+// Any input may be rejected, but parsing and solving must not panic.
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = Day9::parse(input) {
+        let _ = Day9::part1(&parsed);
+        let _ = Day9::part2(&parsed);
+    }
+});
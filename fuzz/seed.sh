@@ -0,0 +1,13 @@
+#!/bin/sh
+# Seed each day's corpus with its sample files, then fuzz, e.g.
+#
+#   fuzz/seed.sh && cargo fuzz run day7 -- -timeout=5 -rss_limit_mb=512 -max_len=65536
+#
+# `-timeout` turns a solver that never finishes into a crash, and
+# `-rss_limit_mb` does the same for runaway allocation.
+cd "$(dirname "$0")/.." || exit 1
+for input in day*-sample*.txt; do
+    day=${input%%[-.]*}
+    mkdir -p "fuzz/corpus/$day"
+    cp "$input" "fuzz/corpus/$day/"
+done
@@ -0,0 +1,247 @@
+//! Find many patterns in one pass over the text with an Aho-Corasick automaton.
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// Where pattern number `pattern` was found: `text[start..end]`, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Match {
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// A matcher for a fixed set of byte patterns, built once and then run over any
+/// number of texts without allocating.
+///
+/// Every state has a transition for every byte, so scanning is one table lookup per
+/// byte. A state's `outputs` are the patterns ending there, including those that are
+/// suffixes of the text so far, longest first.
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    next: Vec<[u32; 256]>,
+    outputs: Vec<Range<usize>>,
+    found: Vec<usize>,
+    lengths: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// Panics if a pattern is empty.
+    pub fn new<I, P>(patterns: I) -> AhoCorasick
+        where I: IntoIterator<Item = P>, P: AsRef<[u8]>
+    {
+        // The trie, with 0 as the root and u32::MAX for a missing edge.
+        let mut next = vec![[u32::MAX; 256]];
+        let mut ends: Vec<Vec<usize>> = vec![vec![]];
+        let mut lengths = Vec::new();
+        for (id, pattern) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref();
+            assert!(!pattern.is_empty(), "pattern {id} is empty");
+            let mut state = 0;
+            for &b in pattern {
+                if next[state][b as usize] == u32::MAX {
+                    next[state][b as usize] = next.len() as u32;
+                    next.push([u32::MAX; 256]);
+                    ends.push(vec![]);
+                }
+                state = next[state][b as usize] as usize;
+            }
+            ends[state].push(id);
+            lengths.push(pattern.len());
+        }
+
+        // Breadth first, so a state's failure link is done before its children need it.
+        // Missing edges take the failure link's edge instead, which makes it a full DFA.
+        let mut fail = vec![0; next.len()];
+        let mut queue = VecDeque::new();
+        for edge in next[0].iter_mut() {
+            match *edge {
+                u32::MAX => *edge = 0,
+                child => queue.push_back(child as usize),
+            }
+        }
+        while let Some(state) = queue.pop_front() {
+            let suffix = ends[fail[state]].clone();
+            ends[state].extend(suffix);
+            let fallback = next[fail[state]];
+            for (edge, &otherwise) in next[state].iter_mut().zip(&fallback) {
+                match *edge {
+                    u32::MAX => *edge = otherwise,
+                    child => {
+                        fail[child as usize] = otherwise as usize;
+                        queue.push_back(child as usize);
+                    }
+                }
+            }
+        }
+
+        let mut found = Vec::new();
+        let outputs = ends.into_iter()
+            .map(|ids| {
+                let start = found.len();
+                found.extend(ids);
+                start..found.len()
+            })
+            .collect();
+        AhoCorasick { next, outputs, found, lengths }
+    }
+
+    /// How many patterns there are; they're numbered from 0 in the order given.
+    pub fn len(&self) -> usize {
+        self.lengths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lengths.is_empty()
+    }
+
+    /// Every match in `text`, overlapping or not, in order of where they end; matches
+    /// ending at the same place come longest first.
+    pub fn find_overlapping<'a>(&'a self, text: &'a [u8]) -> FindOverlapping<'a> {
+        FindOverlapping { automaton: self, text, pos: 0, state: 0, pending: 0..0 }
+    }
+
+    /// Matches that don't overlap, going left to right and taking whichever starts
+    /// first each time, the longest if several do.
+    pub fn find_leftmost_longest<'a>(&'a self, text: &'a [u8]) -> impl Iterator<Item = Match> + 'a {
+        self.non_overlapping(text, AhoCorasick::first)
+    }
+
+    /// Matches that don't overlap, going left to right and taking whichever ends
+    /// first each time, the longest if several do.
+    pub fn find_earliest<'a>(&'a self, text: &'a [u8]) -> impl Iterator<Item = Match> + 'a {
+        self.non_overlapping(text, |automaton, text| automaton.find_overlapping(text).next())
+    }
+
+    /// Repeatedly take the match `pick` chooses, then look again after it. Searching
+    /// afresh each time rescans at most one pattern's length, and needs no buffer.
+    fn non_overlapping<'a, F>(&'a self, text: &'a [u8], pick: F) -> impl Iterator<Item = Match> + 'a
+        where F: Fn(&AhoCorasick, &[u8]) -> Option<Match> + 'a
+    {
+        let mut cursor = 0;
+        std::iter::from_fn(move || {
+            let m = pick(self, &text[cursor..])?;
+            let m = Match { pattern: m.pattern, start: cursor + m.start, end: cursor + m.end };
+            cursor = m.end;
+            Some(m)
+        })
+    }
+
+    /// The match starting earliest in `text`, and the longest of those.
+    pub fn first(&self, text: &[u8]) -> Option<Match> {
+        let longest = self.lengths.iter().copied().max().unwrap_or(0);
+        let mut best: Option<Match> = None;
+        for m in self.find_overlapping(text) {
+            // Nothing ending this far along can start before the best so far.
+            if best.is_some_and(|b| m.end > b.start + longest) {
+                break;
+            }
+            if best.is_none_or(|b| m.start < b.start || (m.start == b.start && m.end > b.end)) {
+                best = Some(m);
+            }
+        }
+        best
+    }
+
+    /// The match starting latest in `text`, and the longest of those.
+    pub fn last(&self, text: &[u8]) -> Option<Match> {
+        self.find_overlapping(text)
+            .reduce(|best, m| if m.start > best.start || (m.start == best.start && m.end > best.end) { m } else { best })
+    }
+}
+
+/// The iterator returned by `AhoCorasick::find_overlapping`.
+pub struct FindOverlapping<'a> {
+    automaton: &'a AhoCorasick,
+    text: &'a [u8],
+    pos: usize,
+    state: usize,
+    pending: Range<usize>,
+}
+
+impl Iterator for FindOverlapping<'_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        let automaton = self.automaton;
+        while self.pending.is_empty() {
+            let &b = self.text.get(self.pos)?;
+            self.state = automaton.next[self.state][b as usize] as usize;
+            self.pos += 1;
+            self.pending = automaton.outputs[self.state].clone();
+        }
+        let pattern = automaton.found[self.pending.next()?];
+        Some(Match { pattern, start: self.pos - automaton.lengths[pattern], end: self.pos })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn matches(automaton: &AhoCorasick, text: &str) -> Vec<(usize, usize, usize)> {
+        automaton.find_overlapping(text.as_bytes()).map(|m| (m.pattern, m.start, m.end)).collect()
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let ac = AhoCorasick::new(["he", "she", "his", "hers"]);
+        assert_eq!(matches(&ac, "ushers"), vec![(1, 1, 4), (0, 2, 4), (3, 2, 6)]);
+        assert_eq!(matches(&ac, "xyz"), vec![]);
+        let ac = AhoCorasick::new(["one", "eight", "two", "1"]);
+        assert_eq!(matches(&ac, "oneightwo1"), vec![(0, 0, 3), (1, 2, 7), (2, 6, 9), (3, 9, 10)]);
+    }
+
+    #[test]
+    fn test_first_last() {
+        let ac = AhoCorasick::new(["bc", "abcd", "c", "d"]);
+        assert_eq!(ac.first(b"xabcd").map(|m| m.range()), Some(1..5));
+        assert_eq!(ac.last(b"xabcd").map(|m| m.range()), Some(4..5));
+        assert_eq!(ac.first(b"xyz"), None);
+        assert_eq!(ac.last(b""), None);
+        assert!(AhoCorasick::new(Vec::<&str>::new()).first(b"abc").is_none());
+    }
+
+    #[test]
+    fn test_non_overlapping() {
+        let ac = AhoCorasick::new(["bc", "abcd", "c", "d", "de"]);
+        let ranges = |ms: &mut dyn Iterator<Item = Match>| ms.map(|m| m.range()).collect::<Vec<_>>();
+        assert_eq!(ranges(&mut ac.find_leftmost_longest(b"abcde")), vec![0..4]);
+        assert_eq!(ranges(&mut ac.find_earliest(b"abcde")), vec![1..3, 3..4]);
+        assert_eq!(ranges(&mut ac.find_leftmost_longest(b"xcdc")), vec![1..2, 2..3, 3..4]);
+        assert_eq!(ranges(&mut ac.find_earliest(b"")), vec![]);
+    }
+
+    #[test]
+    fn test_duplicates_and_utf8() {
+        let ac = AhoCorasick::new(["ö", "ö", "o"]);
+        assert_eq!(matches(&ac, "oö"), vec![(2, 0, 1), (0, 1, 3), (1, 1, 3)]);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_naive_search(patterns in prop::collection::vec("[ab]{1,3}", 1..5), text in "[abc]{0,20}") {
+            let ac = AhoCorasick::new(&patterns);
+            let mut found: Vec<Match> = ac.find_overlapping(text.as_bytes()).collect();
+            let mut naive = Vec::new();
+            for (pattern, p) in patterns.iter().enumerate() {
+                for start in 0..text.len() {
+                    if text[start..].starts_with(p.as_str()) {
+                        naive.push(Match { pattern, start, end: start + p.len() });
+                    }
+                }
+            }
+            let key = |m: &Match| (m.start, m.end, m.pattern);
+            prop_assert_eq!(ac.first(text.as_bytes()), naive.iter().copied().min_by_key(|m| (m.start, std::cmp::Reverse(m.end), m.pattern)));
+            found.sort_by_key(key);
+            naive.sort_by_key(key);
+            prop_assert_eq!(found, naive);
+        }
+    }
+}
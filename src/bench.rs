@@ -0,0 +1,187 @@
+//! Time each phase of a solution and compare the results against a saved baseline.
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use crate::error::Result;
+use crate::solution::Solution;
+
+/// Summary of a phase's timings, in microseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub mean_us: f64,
+    pub median_us: f64,
+    pub stddev_us: f64,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut us: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1e6).collect();
+        if us.is_empty() {
+            return Stats { mean_us: 0.0, median_us: 0.0, stddev_us: 0.0 };
+        }
+        us.sort_by(f64::total_cmp);
+        let n = us.len() as f64;
+        let mean = us.iter().sum::<f64>() / n;
+        let median = if us.len() % 2 == 1 {
+            us[us.len() / 2]
+        } else {
+            (us[us.len() / 2 - 1] + us[us.len() / 2]) / 2.0
+        };
+        let variance = us.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        Stats { mean_us: mean, median_us: median, stddev_us: variance.sqrt() }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part 1",
+            Phase::Part2 => "part 2",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u32,
+    pub phase: Phase,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+/// Time parsing and both parts of `S` on `input`, `iterations` times each.
+pub fn time<S: Solution>(input: &str, iterations: usize) -> Result<[Stats; 3]> {
+    let mut samples = [vec![], vec![], vec![]];
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(black_box(input))?);
+        samples[0].push(start.elapsed());
+        let start = Instant::now();
+        black_box(S::part1(&parsed)?);
+        samples[1].push(start.elapsed());
+        let start = Instant::now();
+        black_box(S::part2(&parsed)?);
+        samples[2].push(start.elapsed());
+    }
+    Ok(samples.map(|s| Stats::new(&s)))
+}
+
+/// What `aoc bench --json` writes and `--baseline` reads back.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub timings: Vec<Timing>,
+}
+
+impl Report {
+    pub fn load<P: AsRef<Path>>(path: P) -> std::result::Result<Report, String> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+        serde_json::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), String> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, json + "\n").map_err(|e| format!("cannot write {}: {e}", path.display()))
+    }
+}
+
+/// A phase whose median got slower than its baseline by more than the allowed threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u32,
+    pub phase: Phase,
+    pub baseline_us: f64,
+    pub current_us: f64,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let change = (self.current_us / self.baseline_us - 1.0) * 100.0;
+        write!(f, "day {} {}: {} -> {} (+{change:.0}%)",
+               self.day, self.phase, Micros(self.baseline_us), Micros(self.current_us))
+    }
+}
+
+/// Compare medians of phases found in both reports; `threshold` is a fraction, e.g. 0.1 for 10%.
+pub fn compare(baseline: &Report, current: &Report, threshold: f64) -> Vec<Regression> {
+    current.timings.iter()
+        .filter_map(|t| {
+            let base = baseline.timings.iter().find(|b| b.day == t.day && b.phase == t.phase)?;
+            (t.stats.median_us > base.stats.median_us * (1.0 + threshold)).then_some(Regression {
+                day: t.day,
+                phase: t.phase,
+                baseline_us: base.stats.median_us,
+                current_us: t.stats.median_us,
+            })
+        })
+        .collect()
+}
+
+/// A duration in microseconds, printed in whichever unit keeps it readable.
+pub struct Micros(pub f64);
+
+impl fmt::Display for Micros {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self.0 {
+            us if us < 1e3 => format!("{us:.1}µs"),
+            us if us < 1e6 => format!("{:.2}ms", us / 1e3),
+            us => format!("{:.2}s", us / 1e6),
+        };
+        f.pad(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(day: u32, phase: Phase, median_us: f64) -> Timing {
+        Timing { day, phase, stats: Stats { mean_us: median_us, median_us, stddev_us: 0.0 } }
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&[2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_micros));
+        assert_eq!(stats, Stats { mean_us: 5.0, median_us: 4.5, stddev_us: 2.0 });
+        assert_eq!(Stats::new(&[Duration::from_micros(3)]).median_us, 3.0);
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Report { iterations: 5, timings: vec![timing(1, Phase::Parse, 100.0), timing(1, Phase::Part1, 100.0)] };
+        let current = Report {
+            iterations: 5,
+            timings: vec![timing(1, Phase::Parse, 105.0), timing(1, Phase::Part1, 150.0), timing(2, Phase::Parse, 1.0)],
+        };
+        let regressions = compare(&baseline, &current, 0.1);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].to_string(), "day 1 part 1: 100.0µs -> 150.0µs (+50%)");
+    }
+
+    #[test]
+    fn test_json() {
+        let report = Report { iterations: 5, timings: vec![timing(8, Phase::Part2, 2500.0)] };
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains(r#""phase":"part2","mean_us":2500.0"#), "{json}");
+        assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+    }
+}
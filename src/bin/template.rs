@@ -1,76 +1,41 @@
-use std::env;
-use std::cmp::Ordering::{self, *};
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
-use std::collections::HashMap;
-use std::collections::HashSet;
-use std::hash::{Hash, Hasher};
-use std::collections::hash_map::DefaultHasher;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use crate::error::{AocError, Result};
+use crate::solution::Solution;
 
-use winnow::prelude::*;
-use winnow::token::*;
-use winnow::combinator::*;
+pub struct DayN;
 
-fn label(input: &mut &str) -> PResult<String> {
-    take_while(1.., ('0'..='9','A'..='Z'))
-        .map(String::from)
-        .parse_next(input)
-}
-
-fn my_hash<T>(obj: T) -> u64
-where
-    T: Hash,
-{
-    let mut hasher = DefaultHasher::new();
-    obj.hash(&mut hasher);
-    hasher.finish()
-}
-
-fn number(input: &mut &str) -> PResult<u32> {
-    take_while(0.., |c: char| c.is_ascii_digit())
-        .try_map(|input| u32::from_str_radix(input, 10))
-        .parse_next(input)
-}
-
-fn multiple_space(input: &mut &str) -> PResult<()> {
-    repeat(1.., ' ')
-        .parse_next(input)
-}
+impl Solution for DayN {
+    type Input = Vec<String>;
+    type Output = usize;
+    const SIZE: usize = 1000;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if let Ok(mut lines) = read_lines(&args[1]) {
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(String::from).collect())
+    }
 
-        // let mut line: String = lines.next().unwrap().unwrap();
-        // let dirs = directions(&mut line.as_str()).unwrap();
+    fn part1(lines: &Vec<String>) -> Result<usize> {
+        Ok(lines.len())
+    }
 
-        for line in lines {
-            if let Ok(l) = line {
-                // let n = node(&mut l.as_str()).unwrap();
-                // nodes.insert(n.name.clone(), n);
-            }
-        }
-        // println!("{}");
+    fn part2(_lines: &Vec<String>) -> Result<usize> {
+        Err(AocError::puzzle("part 2 isn't solved yet"))
     }
-}
 
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+    /// `size` lines.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size).map(|_| format!("{}\n", rng.gen_range(0..100))).collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_number() {
-        let mut input = "12";
+    const SAMPLE: &str = include_str!("../../dayN-sample.txt");
 
-        let n = number.parse_next(&mut input).unwrap();
-        assert_eq!(n, 12);
+    #[test]
+    fn test_parse() {
+        assert!(DayN::parse(SAMPLE).is_ok());
     }
-
 }
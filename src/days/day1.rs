@@ -0,0 +1,495 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use crate::automaton::{AhoCorasick, Match};
+use crate::error::{AocError, Result};
+use crate::solution::Solution;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// The whole document, so part 1 can scan its bytes without splitting it into lines.
+    type Input = String;
+    type Output = u32;
+    const SIZE: usize = 1000;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(text: &String) -> Result<u32> {
+        calibration_sum(text.as_bytes())
+    }
+
+    fn part2(text: &String) -> Result<u32> {
+        Day1::part2_with(text, &Digits::english())
+    }
+
+    /// `size` lines of letters, digits and spelled digits, each with at least one digit.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut out = String::new();
+        for _ in 0..size {
+            let pieces = rng.gen_range(1..=8);
+            let digit_at = rng.gen_range(0..pieces);
+            for i in 0..pieces {
+                match rng.gen_range(0..3) {
+                    0 => out.extend((0..rng.gen_range(1..=4)).map(|_| rng.gen_range('a'..='z'))),
+                    1 => out.push_str(ENGLISH.choose(rng).unwrap()),
+                    _ => {}
+                }
+                if i == digit_at || rng.gen_bool(0.2) {
+                    out.push(rng.gen_range('1'..='9'));
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl Day1 {
+    /// Part 2 reading the words in `digits`, say from a `Dictionary` for another
+    /// language, instead of English.
+    pub fn part2_with(text: &str, digits: &Digits) -> Result<u32> {
+        text.lines()
+            .enumerate()
+            .map(|(i, l)| digits.calibration_value(l, OverlapPolicy::Overlapping).ok_or_else(|| no_digits(i)))
+            .sum()
+    }
+}
+
+fn no_digits(i: usize) -> AocError {
+    AocError::puzzle(format!("line {} has no digits", i + 1))
+}
+
+/// How to read words that share letters, like `eightwo`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverlapPolicy {
+    /// Every word counts, even where it shares letters with another: `eightwo` is 8 then 2.
+    /// This is the puzzle's reading.
+    #[default]
+    Overlapping,
+    /// Left to right, take the word that starts first, the longest if several do, and
+    /// skip its letters: `eightwo` is just 8.
+    LeftmostLongest,
+    /// Left to right, take each word as soon as it's complete and skip its letters, so
+    /// `seven` is read as `eve` if that's a word too.
+    Greedy,
+}
+
+impl FromStr for OverlapPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "overlapping" => Ok(OverlapPolicy::Overlapping),
+            "leftmost-longest" => Ok(OverlapPolicy::LeftmostLongest),
+            "greedy" => Ok(OverlapPolicy::Greedy),
+            _ => Err(format!("no overlap policy `{s}`; expected overlapping, leftmost-longest or greedy")),
+        }
+    }
+}
+
+/// The words that count as numbers in part 2, each with the number it stands for.
+/// The ASCII digits always count, so a dictionary only needs its words.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dictionary {
+    words: Vec<(String, u32)>,
+}
+
+const ENGLISH: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+impl Dictionary {
+    pub fn new() -> Dictionary {
+        Dictionary { words: Vec::new() }
+    }
+
+    /// `one` to `nine`, as in the puzzle.
+    pub fn english() -> Dictionary {
+        (1..).zip(ENGLISH).fold(Dictionary::new(), |dictionary, (value, word)| dictionary.word(word, value))
+    }
+
+    /// Add `token` for `value`, which may have more than one digit, like `twelve` for 12.
+    ///
+    /// Panics if `token` is empty.
+    pub fn word(mut self, token: impl Into<String>, value: u32) -> Dictionary {
+        let token = token.into();
+        assert!(!token.is_empty(), "empty token for {value}");
+        self.words.push((token, value));
+        self
+    }
+
+    /// One `token = value` per line; blank lines and lines starting with `#` are skipped.
+    pub fn parse(text: &str) -> Result<Dictionary> {
+        let mut dictionary = Dictionary::new();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+            let (token, value) = line.split_once('=')
+                .ok_or_else(|| AocError::parse(i + 1, line, line.len(), "expected `token = value`"))?;
+            let token = token.trim();
+            if token.is_empty() {
+                return Err(AocError::parse(i + 1, line, 0, "expected a token before `=`"));
+            }
+            let offset = line.len() - value.trim_start().len();
+            let value = value.trim().parse()
+                .map_err(|_| AocError::parse(i + 1, line, offset, "expected a number after `=`"))?;
+            dictionary = dictionary.word(token, value);
+        }
+        Ok(dictionary)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Dictionary> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|source| AocError::Io { path: path.into(), source })?;
+        Dictionary::parse(&text).map_err(|e| e.in_file(path))
+    }
+
+    /// The matcher for these words and the ASCII digits.
+    pub fn build(&self) -> Digits {
+        let digits = (0..10).map(|d| (d.to_string(), d));
+        let (tokens, values): (Vec<String>, Vec<u32>) = digits.chain(self.words.iter().cloned()).unzip();
+        Digits { automaton: AhoCorasick::new(tokens), values }
+    }
+}
+
+/// The words and digits that count as numbers in part 2, matched all at once.
+pub struct Digits {
+    automaton: AhoCorasick,
+    values: Vec<u32>,
+}
+
+impl Digits {
+    pub fn english() -> Digits {
+        Dictionary::english().build()
+    }
+
+    /// The number made of the first digit on the line and the last, read according to
+    /// `policy`. A word for a number of several digits reads as those digits, so
+    /// `twelve` alone is 12 and `twelve3` is 13.
+    pub fn calibration_value(&self, l: &str, policy: OverlapPolicy) -> Option<u32> {
+        let (first, last) = self.first_and_last(l, policy)?;
+        Some(self.combine(first, last))
+    }
+
+    /// Everything found on each line of `input` and what it's read as; lines with no
+    /// digits are listed rather than being an error.
+    pub fn explain<'a>(&self, input: &'a str, policy: OverlapPolicy) -> Explanation<'a> {
+        let lines = input.lines()
+            .map(|line| {
+                let matches = self.automaton.find_overlapping(line.as_bytes())
+                    .map(|m| (m, self.values[m.pattern]))
+                    .collect();
+                let chosen = self.first_and_last(line, policy);
+                let value = chosen.map(|(first, last)| self.combine(first, last));
+                LineExplanation { line, matches, chosen, value }
+            })
+            .collect();
+        Explanation { lines }
+    }
+
+    fn first_and_last(&self, l: &str, policy: OverlapPolicy) -> Option<(Match, Match)> {
+        let text = l.as_bytes();
+        match policy {
+            OverlapPolicy::Overlapping => Some((self.automaton.first(text)?, self.automaton.last(text)?)),
+            OverlapPolicy::LeftmostLongest => first_and_last(self.automaton.find_leftmost_longest(text)),
+            OverlapPolicy::Greedy => first_and_last(self.automaton.find_earliest(text)),
+        }
+    }
+
+    fn combine(&self, first: Match, last: Match) -> u32 {
+        leading_digit(self.values[first.pattern]) * 10 + self.values[last.pattern] % 10
+    }
+}
+
+/// How each line of a calibration document was read, from `Digits::explain`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    pub lines: Vec<LineExplanation<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineExplanation<'a> {
+    pub line: &'a str,
+    /// Every digit and word on the line, overlapping or not, with its value.
+    pub matches: Vec<(Match, u32)>,
+    /// The matches the first and last digits were taken from.
+    pub chosen: Option<(Match, Match)>,
+    /// What the line adds to the total; `None` if it has no digits.
+    pub value: Option<u32>,
+}
+
+impl Explanation<'_> {
+    pub fn total(&self) -> u64 {
+        self.lines.iter().filter_map(|l| l.value).map(u64::from).sum()
+    }
+
+    /// The 1-based numbers of the lines with no digits.
+    pub fn missing(&self) -> Vec<usize> {
+        self.lines.iter().enumerate().filter(|(_, l)| l.value.is_none()).map(|(i, _)| i + 1).collect()
+    }
+}
+
+impl fmt::Display for Explanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, line) in self.lines.iter().enumerate() {
+            writeln!(f, "line {}: {}", i + 1, line.line)?;
+            write!(f, "{line}")?;
+        }
+        write!(f, "total: {}", self.total())?;
+        match self.missing().as_slice() {
+            [] => Ok(()),
+            missing => {
+                let missing: Vec<String> = missing.iter().map(usize::to_string).collect();
+                write!(f, "\n!! no digits on line {}", missing.join(", "))
+            }
+        }
+    }
+}
+
+/// The matches as `text start..end = value`, then the line's reading, one per line.
+impl fmt::Display for LineExplanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let describe = |m: &Match| format!("{} {}..{}", &self.line[m.range()], m.start, m.end);
+        for (m, value) in &self.matches {
+            writeln!(f, "  {} = {value}", describe(m))?;
+        }
+        match (self.chosen, self.value) {
+            (Some((first, last)), Some(value)) =>
+                writeln!(f, "  first {}, last {}: adds {value}", describe(&first), describe(&last)),
+            _ => writeln!(f, "  !! no digits"),
+        }
+    }
+}
+
+fn leading_digit(mut n: u32) -> u32 {
+    while n >= 10 {
+        n /= 10;
+    }
+    n
+}
+
+fn first_and_last(mut matches: impl Iterator<Item = Match>) -> Option<(Match, Match)> {
+    let first = matches.next()?;
+    Some((first, matches.last().unwrap_or(first)))
+}
+
+/// The first and last digit on one line, a character at a time. `calibration_sum`
+/// does the same for a whole document much faster.
+pub fn calibration_value(l: &str) -> Option<u32> {
+    let mut first: Option<u32> = None;
+    let mut last: Option<u32> = None;
+    for c in l.chars() {
+        if let Some(num) = c.to_digit(10) {
+            if first.is_none() {
+                first = Some(num);
+            }
+            last = Some(num);
+        }
+    }
+    Some(first? * 10 + last?)
+}
+
+/// The sum of every line's calibration value, found straight from the bytes: for each
+/// line, search forward for its first digit, then back from its end for its last.
+///
+/// The searches look at eight bytes at a time, which the compiler turns into a few
+/// wide instructions, and only drop to single bytes for the last few of the text.
+pub fn calibration_sum(text: &[u8]) -> Result<u32> {
+    let mut sum: u32 = 0;
+    let mut start = 0;
+    let mut line = 0;
+    while start < text.len() {
+        let first = find(text, start, |w| digit_mask(w) | newline_mask(w), |b| b.is_ascii_digit() || b == b'\n')
+            .filter(|&i| text[i] != b'\n')
+            .ok_or_else(|| no_digits(line))?;
+        let end = find(text, first, newline_mask, |b| b == b'\n').unwrap_or(text.len());
+        // There's a digit at `first`, so the search back stops there at the latest.
+        let last = rfind(&text[..end], digit_mask, |b| b.is_ascii_digit()).unwrap();
+        let value = u32::from(text[first] - b'0') * 10 + u32::from(text[last] - b'0');
+        sum = sum.checked_add(value).ok_or_else(|| AocError::overflow("sum of calibration values"))?;
+        start = end + 1;
+        line += 1;
+    }
+    Ok(sum)
+}
+
+const ONES: u64 = u64::from_le_bytes([0x01; 8]);
+const HIGHS: u64 = ONES * 0x80;
+
+/// The top bit of each byte of `word` that's an ASCII digit, and no others.
+fn digit_mask(word: u64) -> u64 {
+    // "Determine if a word has a byte between m and n" from Bit Twiddling Hacks, for
+    // bytes strictly between '/' and ':'. Working on the low seven bits of each byte
+    // leaves room so nothing carries or borrows into the next byte.
+    let low = word & (ONES * 0x7f);
+    (ONES * (0x7f + u64::from(b':')) - low) & !word & (low + ONES * (0x7f - u64::from(b'/'))) & HIGHS
+}
+
+/// The top bit of each byte of `word` that's `\n`. A borrow can mark bytes after a
+/// real one too, so only the lowest bit set is sure to be right.
+fn newline_mask(word: u64) -> u64 {
+    let x = word ^ (ONES * u64::from(b'\n'));
+    x.wrapping_sub(ONES) & !x & HIGHS
+}
+
+/// The first index from `start` whose byte `is` matches; `mask` marks such bytes in
+/// an eight byte little-endian word, and must be right about the lowest one.
+fn find(text: &[u8], start: usize, mask: fn(u64) -> u64, is: fn(u8) -> bool) -> Option<usize> {
+    let mut chunks = text[start..].chunks_exact(8);
+    for (k, chunk) in chunks.by_ref().enumerate() {
+        let m = mask(u64::from_le_bytes(chunk.try_into().unwrap()));
+        if m != 0 {
+            return Some(start + k * 8 + m.trailing_zeros() as usize / 8);
+        }
+    }
+    let rest = chunks.remainder();
+    rest.iter().position(|&b| is(b)).map(|i| text.len() - rest.len() + i)
+}
+
+/// The last index in `text` whose byte `is` matches; `mask` marks such bytes in an
+/// eight byte little-endian word, and must be right about all of them.
+fn rfind(text: &[u8], mask: fn(u64) -> u64, is: fn(u8) -> bool) -> Option<usize> {
+    let mut chunks = text.rchunks_exact(8);
+    for (k, chunk) in chunks.by_ref().enumerate() {
+        let m = mask(u64::from_le_bytes(chunk.try_into().unwrap()));
+        if m != 0 {
+            return Some(text.len() - (k + 1) * 8 + (63 - m.leading_zeros() as usize) / 8);
+        }
+    }
+    chunks.remainder().iter().rposition(|&b| is(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_calibration_value() {
+        assert_eq!(calibration_value("pqr3stu8vwx"), Some(38));
+        assert_eq!(calibration_value("treb7uchet"), Some(77));
+        assert_eq!(calibration_value("eightwothree"), None);
+    }
+
+    #[test]
+    fn test_calibration_sum() {
+        assert_eq!(calibration_sum(b"1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n").unwrap(), 142);
+        assert_eq!(calibration_sum(b"treb7uchet").unwrap(), 77);
+        assert_eq!(calibration_sum(b"").unwrap(), 0);
+        assert_eq!(calibration_sum("héllo wörld 1 and then a long way to 9\r\n".as_bytes()).unwrap(), 19);
+        let e = calibration_sum(b"12\nabcdefghijklmnop\n3").unwrap_err();
+        assert_eq!(e.to_string(), "invalid puzzle input: line 2 has no digits");
+        assert!(calibration_sum(b"1\n\n2").is_err());
+    }
+
+    #[test]
+    fn test_masks() {
+        for b in 0..=255u8 {
+            for at in 0..8 {
+                let mut bytes = [b'x'; 8];
+                bytes[at] = b;
+                let word = u64::from_le_bytes(bytes);
+                let expected = if b.is_ascii_digit() { 0x80 << (8 * at) } else { 0 };
+                assert_eq!(digit_mask(word), expected, "{b} at {at}");
+                assert_eq!(newline_mask(word).trailing_zeros() as usize / 8, if b == b'\n' { at } else { 8 }, "{b} at {at}");
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn calibration_sum_matches_lines(lines in prop::collection::vec("[a-z0-9é]{0,20}[0-9][a-z0-9\\n]{0,20}", 0..20)) {
+            let text = lines.join("\n");
+            let by_line: Option<u32> = text.lines().map(calibration_value).sum();
+            prop_assert_eq!(calibration_sum(text.as_bytes()).ok(), by_line);
+        }
+    }
+
+    #[test]
+    fn test_spelled_calibration_value() {
+        let digits = Digits::english();
+        let value = |l| digits.calibration_value(l, OverlapPolicy::Overlapping);
+        assert_eq!(value("two1nine"), Some(29));
+        assert_eq!(value("zoneight234"), Some(14));
+        assert_eq!(value("7pqrstsixteen"), Some(76));
+        assert_eq!(value("abc"), None);
+        assert_eq!(value("é1ö"), Some(11));
+    }
+
+    #[test]
+    fn test_overlap_policies() {
+        let english = Digits::english();
+        let values = |digits: &Digits, l| {
+            [OverlapPolicy::Overlapping, OverlapPolicy::LeftmostLongest, OverlapPolicy::Greedy]
+                .map(|policy| digits.calibration_value(l, policy))
+        };
+        assert_eq!(values(&english, "eightwo"), [Some(82), Some(88), Some(88)]);
+        assert_eq!(values(&english, "oneight"), [Some(18), Some(11), Some(11)]);
+        assert_eq!(values(&english, "xtwoneight7"), [Some(27), Some(27), Some(27)]);
+        assert_eq!(values(&english, "3twone"), [Some(31), Some(32), Some(32)]);
+        assert_eq!(values(&english, "zero"), [None, None, None]);
+        // Only the greedy reading finds `eve` in `seven`, because it ends first.
+        let words = Dictionary::new().word("seven", 7).word("eve", 3).build();
+        assert_eq!(values(&words, "seven1"), [Some(71), Some(71), Some(31)]);
+    }
+
+    #[test]
+    fn test_dictionary() {
+        let french = Dictionary::parse("# French\nun = 1\ndeux=2\n\n  trois = 3\ndix = 10\ndouze = 12\n").unwrap();
+        assert_eq!(french, Dictionary::new().word("un", 1).word("deux", 2).word("trois", 3).word("dix", 10).word("douze", 12));
+        let digits = french.build();
+        let value = |l| digits.calibration_value(l, OverlapPolicy::Overlapping);
+        assert_eq!(value("xdeuxytroisz"), Some(23));
+        assert_eq!(value("douze"), Some(12));
+        assert_eq!(value("dix7"), Some(17));
+        assert_eq!(value("4dix"), Some(40));
+        assert_eq!(value("one"), None);
+        assert_eq!(value("0"), Some(0));
+
+        let e = Dictionary::parse("un = 1\ndeux\n").unwrap_err();
+        assert!(e.to_string().starts_with("parse error at line 2, column 5: expected `token = value`"), "{e}");
+        let e = Dictionary::parse("un = one\n").unwrap_err();
+        assert!(e.to_string().starts_with("parse error at line 1, column 6: expected a number"), "{e}");
+        assert!(Dictionary::parse(" = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_part2_with() {
+        let french = Dictionary::parse(include_str!("../../tests/fixtures/french.txt")).unwrap().build();
+        let text = include_str!("../../tests/fixtures/day1-french.txt");
+        assert_eq!(Day1::part2_with(text, &french).unwrap(), 257);
+        assert!(Day1::part2(&text.to_string()).is_err());
+    }
+
+    #[test]
+    fn test_explain() {
+        let explanation = Digits::english().explain("two1nine\nabc\noneight\n", OverlapPolicy::Overlapping);
+        assert_eq!(explanation.total(), 29 + 18);
+        assert_eq!(explanation.missing(), vec![2]);
+        assert_eq!(explanation.lines[2].matches.iter().map(|&(m, v)| (m.range(), v)).collect::<Vec<_>>(),
+                   vec![(0..3, 1), (2..7, 8)]);
+        assert_eq!(explanation.to_string(), "\
+line 1: two1nine
+  two 0..3 = 2
+  1 3..4 = 1
+  nine 4..8 = 9
+  first two 0..3, last nine 4..8: adds 29
+line 2: abc
+  !! no digits
+line 3: oneight
+  one 0..3 = 1
+  eight 2..7 = 8
+  first one 0..3, last eight 2..7: adds 18
+total: 47
+!! no digits on line 2");
+        let explanation = Digits::english().explain("oneight", OverlapPolicy::Greedy);
+        assert_eq!(explanation.lines[0].value, Some(11));
+        assert_eq!(explanation.lines[0].matches.len(), 2);
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use crate::error::{AocError, Result};
+use crate::geom::{self, Point, COMPASS4, COMPASS8, EAST, NORTH, SOUTH, WEST};
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Field;
+    type Output = usize;
+    const SIZE: usize = 140;
+
+    fn parse(input: &str) -> Result<Field> {
+        let tiles = Grid::parse(input, |c| "|-LJ7F.S".contains(c).then_some(c))?;
+        Ok(Field { tiles })
+    }
+
+    fn part1(grid: &Field) -> Result<usize> {
+        let heading = grid.start_heading()?;
+        let count = grid.follow_pipe(heading, 'S').ok_or_else(no_loop)?;
+        Ok(count as usize / 2)
+    }
+
+    fn part2(grid: &Field) -> Result<usize> {
+        // The loop is a polygon through the centres of its tiles; the tiles it encloses are the
+        // lattice points strictly inside it.
+        let trail = grid.trail(grid.start_heading()?).ok_or_else(no_loop)?;
+        Ok(geom::interior_points(&trail) as usize)
+    }
+
+    /// A field about `size` tiles square with one closed loop through `S` and junk pipe elsewhere.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        format!("{}\n", random_loop(rng, size))
+    }
+}
+
+const N: u8 = 1;
+const E: u8 = 2;
+const S: u8 = 4;
+const W: u8 = 8;
+
+/// Grow a blob of cells on a coarse grid without holes or cells touching only at a
+/// corner; its outline, drawn through the tiles at double resolution, is a simple loop.
+fn random_loop(rng: &mut StdRng, size: usize) -> Field {
+    // `n` coarse cells a side, the outermost ring always empty.
+    let n = (size.max(7) - 1) / 2;
+    let mut blob = Grid::new(n, n, false);
+    let center = Point::new(n as i32 / 2, n as i32 / 2);
+    let mut cells = vec![center];
+    blob.set(center, true);
+    let target = ((n - 2) * (n - 2) * 2 / 5).max(1);
+    for _ in 0..target * 20 {
+        if cells.len() >= target {
+            break;
+        }
+        let cell = *cells.choose(rng).unwrap() + *COMPASS4.choose(rng).unwrap();
+        let edge = n as i32 - 1;
+        let on_edge = cell.x == 0 || cell.y == 0 || cell.x == edge || cell.y == edge;
+        if on_edge || blob.get(cell) != Some(&false) || !keeps_loop_simple(&blob, cell) {
+            continue;
+        }
+        blob.set(cell, true);
+        cells.push(cell);
+    }
+
+    let mut conn = Grid::new(2 * n + 1, 2 * n + 1, 0u8);
+    let mut connect = |x, y, dirs| {
+        if let Some(c) = conn.get_mut(Point::new(x, y)) {
+            *c |= dirs;
+        }
+    };
+    for (p, &inside) in blob.iter() {
+        if blob.get(p + EAST).is_some_and(|&next| next != inside) {
+            let x = 2 * p.x + 2;
+            connect(x, 2 * p.y, S);
+            connect(x, 2 * p.y + 1, N | S);
+            connect(x, 2 * p.y + 2, N);
+        }
+        if blob.get(p + SOUTH).is_some_and(|&next| next != inside) {
+            let y = 2 * p.y + 2;
+            connect(2 * p.x, y, E);
+            connect(2 * p.x + 1, y, E | W);
+            connect(2 * p.x + 2, y, W);
+        }
+    }
+
+    const JUNK: &[char] = &['.', '.', '.', '.', '|', '-', 'L', 'J', '7', 'F'];
+    let mut tiles = conn.map(|&c| match c {
+        c if c == N | S => '|',
+        c if c == E | W => '-',
+        c if c == N | E => 'L',
+        c if c == N | W => 'J',
+        c if c == S | W => '7',
+        c if c == S | E => 'F',
+        _ => *JUNK.choose(rng).unwrap(),
+    });
+    let on_loop: Vec<Point> = conn.iter().filter(|(_, &c)| c != 0).map(|(p, _)| p).collect();
+    let &start = on_loop.choose(rng).unwrap();
+    tiles.set(start, 'S');
+    // Junk next to `S` could look like another way out of it.
+    for (p, _) in conn.neighbours4(start).filter(|(_, &c)| c == 0) {
+        tiles.set(p, '.');
+    }
+    Field { tiles }
+}
+
+/// Whether adding `cell` to `blob` keeps its outline a single simple loop: its
+/// neighbours in the blob must be one unbroken run around it, with no corner-only contact.
+fn keeps_loop_simple(blob: &Grid<bool>, cell: Point) -> bool {
+    let at = |d: Point| blob.get(cell + d) == Some(&true);
+    let pinched = COMPASS8.iter().skip(1).step_by(2)
+        .any(|&d| at(d) && !at(Point::new(d.x, 0)) && !at(Point::new(0, d.y)));
+    let runs = (0..8).filter(|&k| at(COMPASS8[k]) && !at(COMPASS8[(k + 7) % 8])).count();
+    !pinched && runs == 1
+}
+
+fn no_loop() -> AocError {
+    AocError::puzzle("no pipe loop through S")
+}
+
+/// The field of pipes; `S` is the start and `.` the ground.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    tiles: Grid<char>,
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+#[derive(Clone)]
+struct Heading { loc: Point, dir: Point }
+
+impl Field {
+    fn get(&self, location: Point) -> Option<char> {
+        self.tiles.get(location).copied()
+    }
+
+    fn try_move(&self, h: &Heading) -> Option<Heading> {
+        let next_loc = h.loc + h.dir;
+        let next_dir = match (h.dir, self.get(next_loc)?) {
+            (NORTH | SOUTH, '|') | (EAST | WEST, '-') => h.dir,
+            (SOUTH, 'L') | (NORTH, 'F') => EAST,
+            (SOUTH, 'J') | (NORTH, '7') => WEST,
+            (EAST, 'J') | (WEST, 'L') => NORTH,
+            (EAST, '7') | (WEST, 'F') => SOUTH,
+            (d, 'S') => d,
+            _ => return None,
+        };
+        Some(Heading { loc: next_loc, dir: next_dir })
+    }
+
+    /// Find the first direction out of `S` that leads back to it.
+    fn start_heading(&self) -> Result<Heading> {
+        let start = self.tiles.find(&'S').ok_or_else(|| AocError::puzzle("no starting tile S"))?;
+        [SOUTH, NORTH, EAST, WEST].into_iter()
+            .map(|dir| Heading { loc: start, dir })
+            .find(|heading| self.follow_pipe(heading.clone(), 'S').is_some())
+            .ok_or_else(no_loop)
+    }
+
+    fn follow_pipe(&self, heading: Heading, finish: char) -> Option<u32> {
+        self.follow_pipe_and(heading, finish, |_, _, _| ())
+    }
+
+    fn follow_pipe_and<F>(&self, mut heading: Heading, finish: char, mut f: F) -> Option<u32>
+        where F: FnMut(&Self, &Heading, &Heading)
+    {
+        let mut count = 0;
+        while let Some(next_heading) = self.try_move(&heading) {
+            count += 1;
+            f(self, &heading, &next_heading);
+            if self.get(next_heading.loc) == Some(finish) {
+                return Some(count);
+            }
+            heading = next_heading;
+        }
+        None
+    }
+
+    /// Every tile on the loop in order, ending with `S`.
+    fn trail(&self, heading: Heading) -> Option<Vec<Point>> {
+        let mut trail = Vec::new();
+        self.follow_pipe_and(heading, 'S', |_, _, h| trail.push(h.loc))?;
+        Some(trail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_follow_pipe() {
+        let grid = Day10::parse(".....\n.S-7.\n.|.|.\n.L-J.\n.....\n").unwrap();
+
+        assert_eq!(grid.tiles.find(&'S'), Some(Point::new(1, 1)));
+        assert_eq!(Day10::part1(&grid).unwrap(), 4);
+        assert_eq!(Day10::part2(&grid).unwrap(), 1);
+        assert!(Day10::part1(&Day10::parse("...\n.S.\n...").unwrap()).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn field_round_trip(rows in prop::collection::vec("[|LJ7F.S-]{7}", 1..12)) {
+            let field = Day10::parse(&rows.join("\n")).unwrap();
+            prop_assert_eq!(field.to_string(), rows.join("\n"));
+            prop_assert_eq!(Day10::parse(&field.to_string()).unwrap(), field);
+        }
+    }
+}
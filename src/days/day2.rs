@@ -0,0 +1,270 @@
+use std::fmt;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use winnow::prelude::*;
+use winnow::combinator::*;
+use winnow::error::{StrContext, StrContextValue};
+use crate::error::{AocError, Result};
+use crate::parse::{expected, lines, literal, number, parse_line};
+use crate::solution::Solution;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Output = u32;
+    const SIZE: usize = 100;
+
+    fn parse(input: &str) -> Result<Vec<Game>> {
+        lines(input, game)
+    }
+
+    fn part1(games: &Vec<Game>) -> Result<u32> {
+        Ok(games.iter()
+            .filter(|g| is_valid(g, 12, 13, 14))
+            .map(|g| g.id)
+            .sum())
+    }
+
+    fn part2(games: &Vec<Game>) -> Result<u32> {
+        games.iter()
+            .try_fold(0u32, |accum, g| {
+                let c = find_min(g);
+                c.0.checked_mul(c.1)?.checked_mul(c.2)?.checked_add(accum)
+            })
+            .ok_or_else(|| AocError::overflow("sum of game powers"))
+    }
+
+    /// `size` games of up to six sets.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut out = String::new();
+        for id in 1..=size as u32 {
+            let sets = (0..rng.gen_range(1..=6))
+                .map(|_| {
+                    let mut colors = [0, 1, 2];
+                    colors.shuffle(rng);
+                    colors[..rng.gen_range(1..=3)].iter()
+                        .map(|c| {
+                            let n = rng.gen_range(1..=20);
+                            match c {
+                                0 => Count(n, 0, 0),
+                                1 => Count(0, n, 0),
+                                _ => Count(0, 0, n),
+                            }
+                        })
+                        .collect()
+                })
+                .collect();
+            out.push_str(&format!("{}\n", Game { id, sets }));
+        }
+        out
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Color {
+    Blue,
+    Red,
+    Green
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Game {
+    id: u32,
+    sets: Vec<Vec<Count>>
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Count(u32,  u32,  u32);
+
+impl std::str::FromStr for Game {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        parse_line(1, input, game)
+    }
+}
+
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (i, set) in self.sets.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            for (j, count) in set.iter().enumerate() {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{count}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Prints as it's written in a set, e.g. `3 blue`; zero counts print as `0 red`.
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let colors = [(self.0, "red"), (self.1, "green"), (self.2, "blue")];
+        let mut counts = colors.iter().filter(|(n, _)| *n > 0).peekable();
+        if counts.peek().is_none() {
+            return write!(f, "0 red");
+        }
+        for (i, (n, color)) in counts.enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{n} {color}")?;
+        }
+        Ok(())
+    }
+}
+
+fn game(input: &mut &str) -> PResult<Game> {
+    let _ = literal("Game ").parse_next(input)?;
+    let id = number.context(expected("game id")).parse_next(input)?;
+    let _ = literal(": ").context(StrContext::Label("after game id")).parse_next(input)?;
+    let sets = sets.parse_next(input)?;
+    let _ = eof.context(expected("', ', '; ' or end of line")).parse_next(input)?;
+    Ok(Game { id, sets })
+}
+
+fn pair(input: &mut &str) -> PResult<Count> {
+    let n = number.parse_next(input)?;
+    let _ = ' '.parse_next(input)?;
+    let c = cut_err(color).parse_next(input)?;
+    Ok(match c {
+        Color::Red => Count(n, 0, 0),
+        Color::Green => Count(0, n, 0),
+        Color::Blue => Count(0, 0, n),
+    })
+}
+
+fn color(input: &mut &str) -> PResult<Color> {
+    alt(
+    ("blue".value(Color::Blue),
+     "red".value(Color::Red),
+     "green".value(Color::Green),
+    ))
+        .context(StrContext::Expected(StrContextValue::StringLiteral("red")))
+        .context(StrContext::Expected(StrContextValue::StringLiteral("green")))
+        .context(StrContext::Expected(StrContextValue::StringLiteral("blue")))
+        .parse_next(input)
+}
+
+fn set(input: &mut &str) -> PResult<Vec<Count>> {
+    separated(0.., pair, ", ").parse_next(input)
+}
+
+fn sets(input: &mut &str) -> PResult<Vec<Vec<Count>>> {
+    separated(0.., set, "; ").parse_next(input)
+}
+
+fn is_valid(game: &Game, red_limit: u32, green_limit: u32, blue_limit: u32) -> bool {
+    for set in &game.sets {
+        for Count(r, g, b) in set {
+            if r > &red_limit || g > &green_limit || b > &blue_limit {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn find_min(game: &Game) -> Count {
+    let mut c = Count(0, 0, 0);
+
+    for set in &game.sets {
+        for Count(r, g, b) in set {
+            if *r > c.0 {
+                c.0 = *r;
+            }
+            if *g > c.1 {
+                c.1 = *g;
+            }
+
+            if *b > c.2 {
+                c.2 = *b;
+            }
+        }
+    }
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_color() {
+        let mut input = "blue";
+
+        let c = color.parse_next(&mut input).unwrap();
+        assert_eq!(c, Color::Blue);
+    }
+
+
+    #[test]
+    fn test_pair() {
+        let mut input = "12 blue, 1 red";
+
+        let p = pair.parse_next(&mut input).unwrap();
+        assert_eq!(Count(0,0,12), p);
+    }
+
+    #[test]
+    fn test_list() {
+        let mut input = "12 blue, 1 red";
+
+        let l = set.parse_next(&mut input).unwrap();
+        assert_eq!(l[1].0, 1);
+        assert_eq!(l.len(), 2);
+    }
+
+    #[test]
+    fn test_sets() {
+        let mut input = "12 blue, 1 red; 1 green";
+
+        let l = sets.parse_next(&mut input).unwrap();
+        assert_eq!(l[0][1].0, 1);
+        assert_eq!(l.len(), 2);
+        assert_eq!(l[1].len(), 1);
+        assert_eq!(l[0].len(), 2);
+    }
+
+    #[test]
+    fn test_game() {
+        let mut input = "Game 3: 12 blue, 1 red";
+
+        let g = game.parse_next(&mut input).unwrap();
+        assert_eq!(g.id, 3);
+        assert_eq!(g.sets.len(), 1);
+        assert_eq!(g.sets[0].len(), 2);
+    }
+
+    #[test]
+    fn test_game_display() {
+        let input = "Game 1: 1 green, 2 blue; 13 red, 2 blue, 3 green; 4 green, 14 red";
+        assert_eq!(input.parse::<Game>().unwrap().to_string(), input);
+    }
+
+    fn count() -> impl Strategy<Value = Count> {
+        (0..100u32, 0..3).prop_map(|(n, c)| match c {
+            0 => Count(n, 0, 0),
+            1 => Count(0, n, 0),
+            _ => Count(0, 0, n),
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn game_round_trip(id in 1..1000u32,
+                           sets in prop::collection::vec(prop::collection::vec(count(), 1..5), 1..5)) {
+            let g = Game { id, sets };
+            prop_assert_eq!(g.to_string().parse::<Game>().unwrap(), g);
+        }
+    }
+}
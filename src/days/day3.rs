@@ -0,0 +1,140 @@
+use std::fmt;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use crate::error::{AocError, Result};
+use crate::geom::Point;
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+    type Output = u32;
+    const SIZE: usize = 140;
+
+    fn parse(input: &str) -> Result<Schematic> {
+        let grid = Grid::parse(input, Some)?;
+        let mut owners = grid.map(|_| None);
+        let mut numbers = Vec::new();
+        for (i, (row, line)) in grid.rows().zip(input.lines()).enumerate() {
+            let mut j = 0;
+            while j < row.len() {
+                let digits = row[j..].iter().take_while(|c| c.is_ascii_digit()).count();
+                if digits == 0 {
+                    j += 1;
+                    continue;
+                }
+                let n = row[j..j + digits].iter().collect::<String>().parse().map_err(|_| {
+                    let offset = line.char_indices().nth(j).map_or(line.len(), |(o, _)| o);
+                    AocError::parse(i + 1, line, offset, "part number too large")
+                })?;
+                for k in j..j + digits {
+                    owners.set(Point::new(k as i32, i as i32), Some(numbers.len()));
+                }
+                numbers.push(n);
+                j += digits;
+            }
+        }
+        Ok(Schematic { grid, numbers, owners })
+    }
+
+    fn part1(schematic: &Schematic) -> Result<u32> {
+        let mut parts: Vec<usize> = schematic.grid.iter()
+            .filter(|(_, &c)| is_symbol(c))
+            .flat_map(|(pos, _)| schematic.adjacent_numbers(pos))
+            .collect();
+        parts.sort();
+        parts.dedup();
+        parts.into_iter()
+            .try_fold(0u32, |accum, k| accum.checked_add(schematic.numbers[k]))
+            .ok_or_else(|| AocError::overflow("sum of part numbers"))
+    }
+
+    fn part2(schematic: &Schematic) -> Result<u32> {
+        schematic.grid.find_all(&'*')
+            .map(|pos| schematic.adjacent_numbers(pos))
+            .filter(|parts| parts.len() == 2)
+            .try_fold(0u32, |accum, parts| {
+                accum.checked_add(schematic.numbers[parts[0]].checked_mul(schematic.numbers[parts[1]])?)
+            })
+            .ok_or_else(|| AocError::overflow("sum of gear ratios"))
+    }
+
+    /// A `size` by `size` schematic; numbers never touch each other on a row.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        const SYMBOLS: &[u8] = b"*#+$/@=%-&";
+        let mut out = String::new();
+        for _ in 0..size {
+            let mut row = vec![b'.'; size];
+            let mut col = 0;
+            while col < size {
+                match rng.gen_range(0..10) {
+                    0..=1 => {
+                        let digits = rng.gen_range(1..=3).min(size - col);
+                        row[col] = rng.gen_range(b'1'..=b'9');
+                        for c in &mut row[col + 1..col + digits] {
+                            *c = rng.gen_range(b'0'..=b'9');
+                        }
+                        // Leave a gap so the next number doesn't run into this one.
+                        col += digits + 1;
+                    }
+                    2 => {
+                        row[col] = *SYMBOLS.choose(rng).unwrap();
+                        col += 1;
+                    }
+                    _ => col += 1,
+                }
+            }
+            out.push_str(std::str::from_utf8(&row).unwrap());
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// The engine schematic, with the part numbers read off it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<u32>,
+    /// For each cell, which of `numbers` it's a digit of.
+    owners: Grid<Option<usize>>,
+}
+
+impl Schematic {
+    /// Indices into `numbers` of the part numbers touching `pos`, each once.
+    fn adjacent_numbers(&self, pos: Point) -> Vec<usize> {
+        let mut adjacent: Vec<usize> = self.owners.neighbours8(pos).filter_map(|(_, &k)| k).collect();
+        adjacent.sort();
+        adjacent.dedup();
+        adjacent
+    }
+}
+
+impl fmt::Display for Schematic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_numbers() {
+        let schematic = Day3::parse("467..114\n...*....\n..35..6.\n").unwrap();
+        assert_eq!(schematic.numbers, vec![467, 114, 35, 6]);
+        assert_eq!(schematic.owners.get(Point::new(2, 0)), Some(&Some(0)));
+        assert_eq!(schematic.owners.get(Point::new(3, 0)), Some(&None));
+        assert_eq!(schematic.adjacent_numbers(Point::new(3, 1)), vec![0, 2]);
+        assert_eq!(Day3::part1(&schematic).unwrap(), 502);
+        assert_eq!(Day3::part2(&schematic).unwrap(), 467 * 35);
+        assert!(Day3::parse("..99999999999\n").unwrap_err().to_string().contains("column 3"));
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use winnow::prelude::*;
+use winnow::combinator::*;
+use crate::error::{AocError, Result};
+use winnow::error::StrContext;
+use crate::parse::{expected, lines, literal, number, number_list, multiple_space, parse_line};
+use crate::solution::Solution;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+    type Output = u32;
+    const SIZE: usize = 200;
+
+    fn parse(input: &str) -> Result<Vec<Card>> {
+        lines(input, card)
+    }
+
+    fn part1(cards: &Vec<Card>) -> Result<u32> {
+        cards.iter()
+            .try_fold(0u32, |accum, c| accum.checked_add(c.value()?))
+            .ok_or_else(|| AocError::overflow("sum of card values"))
+    }
+
+    fn part2(cards: &Vec<Card>) -> Result<u32> {
+        let overflow = || AocError::overflow("number of scratchcards");
+        let mut copies: Vec<u32> = vec![1; cards.len()];
+        for (i, g) in cards.iter().enumerate() {
+            if i + g.matches() >= cards.len() {
+                return Err(AocError::puzzle(format!("card {} wins copies past the end of the table", g.id)));
+            }
+            for j in i + 1..=i + g.matches() {
+                copies[j] = copies[j].checked_add(copies[i]).ok_or_else(overflow)?;
+            }
+        }
+        copies.into_iter()
+            .try_fold(0u32, |accum, n| accum.checked_add(n))
+            .ok_or_else(overflow)
+    }
+
+    /// `size` cards of 10 winning numbers and 25 numbers; no card wins copies past the end.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut out = String::new();
+        let width = size.to_string().len();
+        // Copies grow exponentially, so cards with lots of copies win nothing to keep the total in a u32.
+        let limit = u32::MAX as u64 / (11 * size as u64);
+        let mut copies = vec![1u64; size];
+        for id in 1..=size {
+            let mut pool: Vec<u32> = (1..100).collect();
+            pool.shuffle(rng);
+            let winning = pool[..10].to_vec();
+            // A few matches are common, lots are rare.
+            let mut matches = (0..10).filter(|_| rng.gen_bool(0.3)).count().min(size - id);
+            if copies[id - 1] > limit {
+                matches = 0;
+            }
+            for j in id..id + matches {
+                copies[j] += copies[id - 1];
+            }
+            let mut numbers: Vec<u32> = winning[..matches].iter().chain(&pool[10..35 - matches]).copied().collect();
+            numbers.shuffle(rng);
+            out.push_str(&format!("{:width$}\n", Card { id, winning, numbers }));
+        }
+        out
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Card {
+    id: usize,
+    winning: Vec<u32>,
+    numbers: Vec<u32>
+}
+
+impl std::str::FromStr for Card {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        parse_line(1, input, card)
+    }
+}
+
+/// Numbers are right-aligned in two columns like the puzzle's; a width pads the id,
+/// e.g. `{:3}` gives `Card   1:`.
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Card {:>1$}:", self.id, f.width().unwrap_or(0))?;
+        for n in &self.winning {
+            write!(f, " {n:>2}")?;
+        }
+        write!(f, " |")?;
+        for n in &self.numbers {
+            write!(f, " {n:>2}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Card {
+    fn matches(&self) -> usize {
+        let mut count: usize = 0;
+        for number in &self.numbers {
+            if self.winning.contains(number) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Points for this card, or `None` if they don't fit in a `u32`.
+    fn value(&self) -> Option<u32> {
+        match self.matches() {
+            0 => Some(0),
+            n => 1u32.checked_shl(u32::try_from(n - 1).ok()?)
+        }
+    }
+}
+
+fn card(input: &mut &str) -> PResult<Card> {
+    let _ = literal("Card").parse_next(input)?;
+    multiple_space.parse_next(input)?;
+    let id = number::<u32>.context(expected("card id")).parse_next(input)? as usize;
+    let _ = literal(":").context(StrContext::Label("after card id")).parse_next(input)?;
+    multiple_space.parse_next(input)?;
+    let winning = number_list.parse_next(input)?;
+    let _ = literal(" |").context(StrContext::Label("after winning numbers")).parse_next(input)?;
+    multiple_space.parse_next(input)?;
+    let numbers = number_list.parse_next(input)?;
+    let _ = eof.context(expected("a number or end of line")).parse_next(input)?;
+    Ok(Card { id, winning, numbers })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_card() {
+        let mut input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+
+        let g = card.parse_next(&mut input).unwrap();
+        assert_eq!(g.id, 1);
+        assert_eq!(g.winning.len(), 5);
+        assert_eq!(g.numbers.len(), 8);
+        assert_eq!(g.matches(), 4);
+        assert_eq!(g.value(), Some(8));
+    }
+
+    #[test]
+    fn test_card_error() {
+        let e = "Card 1: 41 48 83 x 1".parse::<Card>().unwrap_err().to_string();
+        assert!(e.starts_with("parse error at line 1, column 17: expected ' |' after winning numbers"), "{e}");
+    }
+
+    #[test]
+    fn test_card_display() {
+        let input = "Card   1: 98 16 95  7 | 85 15  8";
+        assert_eq!(format!("{:3}", input.parse::<Card>().unwrap()), input);
+    }
+
+    proptest! {
+        #[test]
+        fn card_round_trip(id in 1..1000usize,
+                           winning in prop::collection::vec(0..1000u32, 1..10),
+                           numbers in prop::collection::vec(0..1000u32, 1..25)) {
+            let c = Card { id, winning, numbers };
+            prop_assert_eq!(c.to_string().parse::<Card>().unwrap(), c);
+        }
+    }
+}
@@ -0,0 +1,386 @@
+use std::collections::HashMap;
+use std::fmt;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::ops::Range;
+use tracing::{debug, trace};
+use winnow::prelude::*;
+use winnow::token::*;
+use crate::error::{AocError, Result};
+use crate::interval::IntervalSet;
+use winnow::error::StrContext;
+use crate::parse::{expected, literal, number, number_list, parse_line};
+use crate::solution::Solution;
+
+pub struct Day5;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Map>,
+}
+
+impl Solution for Day5 {
+    type Input = Almanac;
+    type Output = u64;
+    const SIZE: usize = 30;
+
+    fn parse(input: &str) -> Result<Almanac> {
+        let mut lines = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+        let seeds = parse_line(1, lines.next().unwrap_or_default().1, seeds)?;
+        let mut maps: Vec<Map> = Vec::new();
+        let mut current_map: Option<Map> = None;
+        for (n, l) in lines {
+            if l.trim().is_empty() {
+                if let Some(map) = current_map.take() {
+                    maps.push(map);
+                }
+            } else {
+                match current_map {
+                    None => current_map = Some(parse_line(n, l, map_header)?),
+                    Some(ref mut map) => map.ranges.push(parse_map_range(n, l)?),
+                }
+            }
+        }
+        if let Some(map) = current_map.take() {
+            maps.push(map);
+        }
+        for map in &mut maps {
+            map.sort();
+        }
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part1(almanac: &Almanac) -> Result<u64> {
+        let maps = almanac.chain()?;
+        let mut lowest_location: u64 = u64::MAX;
+        for &seed in &almanac.seeds {
+            let value = maps.iter().fold(seed, |value, map| map.map(value));
+            lowest_location = value.min(lowest_location);
+        }
+        Ok(lowest_location)
+    }
+
+    fn part2(almanac: &Almanac) -> Result<u64> {
+        let pairs = almanac.seeds.chunks_exact(2);
+        if !pairs.remainder().is_empty() {
+            return Err(AocError::puzzle("seeds must come in start/length pairs"));
+        }
+        let seeds = pairs
+            .map(|pair| {
+                let end = pair[0].checked_add(pair[1]).ok_or_else(|| AocError::overflow("end of seed range"))?;
+                Ok(pair[0]..end)
+            })
+            .collect::<Result<IntervalSet<u64>>>()?;
+        debug!(seeds = ?almanac.seeds, ranges = seeds.iter().count(), "seeds");
+        let locations = almanac.chain()?.iter().fold(seeds, |values, map| {
+            let values = map.map_set(&values);
+            trace!(to = %map.to, ranges = values.iter().count(), min = ?values.min(), "mapped");
+            values
+        });
+        locations.min().ok_or_else(|| AocError::puzzle("every seed range is empty"))
+    }
+
+    /// Ten seed ranges and the seven maps from seed to location with `size` ranges each.
+    ///
+    /// Seed ranges are about as long as the maps' ranges, so most of them get split
+    /// somewhere along the way.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        const CATEGORIES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+        const LIMIT: u64 = 1 << 32;
+        let piece = LIMIT / (size as u64 + 1);
+        let seeds = (0..10)
+            .flat_map(|_| [rng.gen_range(0..LIMIT / 2), rng.gen_range(1..=piece)])
+            .collect();
+        let maps = CATEGORIES.windows(2)
+            .map(|pair| {
+                // Cut [0, LIMIT) into pieces, then lay the same pieces out again in a
+                // shuffled order, so neither sources nor destinations overlap.
+                let mut cuts: Vec<u64> = (0..size.max(1)).map(|_| rng.gen_range(0..LIMIT)).collect();
+                cuts.extend([0, LIMIT]);
+                cuts.sort();
+                cuts.dedup();
+                let pieces: Vec<(u64, u64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+                let mut order: Vec<usize> = (0..pieces.len()).collect();
+                order.shuffle(rng);
+                let mut dest_start = 0;
+                let mut ranges = Vec::new();
+                for i in order {
+                    let (source_start, length) = pieces[i];
+                    if rng.gen_bool(0.9) {
+                        ranges.push(MapRange { dest_start, source_start, length });
+                    }
+                    dest_start += length;
+                }
+                let mut map = Map { from: pair[0].to_string(), to: pair[1].to_string(), ranges };
+                map.sort();
+                map
+            })
+            .collect();
+        format!("{}\n", Almanac { seeds, maps })
+    }
+}
+
+impl Almanac {
+    /// The maps to follow from `seed`, in order.
+    fn chain(&self) -> Result<Vec<&Map>> {
+        let maps: HashMap<&str, &Map> = self.maps.iter().map(|m| (m.from.as_str(), m)).collect();
+        let mut chain = Vec::new();
+        let mut source = "seed";
+        while let Some(&map) = maps.get(source) {
+            if chain.len() == maps.len() {
+                return Err(AocError::puzzle("maps from `seed` form a cycle"));
+            }
+            chain.push(map);
+            source = &map.to;
+        }
+        Ok(chain)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Map {
+    from: String,
+    to: String,
+    ranges: Vec<MapRange>
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MapRange {
+    dest_start: u64,
+    source_start: u64,
+    length: u64,
+}
+
+/// A whole map: the header line followed by its ranges.
+impl std::str::FromStr for Map {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut lines = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+        let mut map = parse_line(1, lines.next().unwrap_or_default().1, map_header)?;
+        for (n, l) in lines {
+            map.ranges.push(parse_map_range(n, l)?);
+        }
+        map.sort();
+        Ok(map)
+    }
+}
+
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "seeds:")?;
+        for seed in &self.seeds {
+            write!(f, " {seed}")?;
+        }
+        for map in &self.maps {
+            write!(f, "\n\n{map}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-to-{} map:", self.from, self.to)?;
+        for range in &self.ranges {
+            write!(f, "\n{range}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for MapRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.dest_start, self.source_start, self.length)
+    }
+}
+
+impl std::str::FromStr for MapRange {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        parse_line(1, input, map_range)
+    }
+}
+
+fn seeds(input: &mut &str) -> PResult<Vec<u64>> {
+    let _ = literal("seeds: ").parse_next(input)?;
+    number_list(input)
+}
+
+impl MapRange {
+    fn map(&self, source: u64) -> Option<u64> {
+        let range = self.source_start..(self.source_start + self.length);
+        if range.contains(&source) {
+            Some(source - self.source_start + self.dest_start)
+        } else {
+            None
+        }
+    }
+}
+
+impl MapRange {
+    fn source_range(&self) -> Range<u64> {
+        self.source_start..(self.source_start + self.length)
+    }
+
+    /// Where the part of `source` this range covers ends up, if it covers any.
+    fn map_range(&self, source: &Range<u64>) -> Option<Range<u64>> {
+        let (start, end) = (source.start.max(self.source_start), source.end.min(self.source_start + self.length));
+        (start < end).then(|| start - self.source_start + self.dest_start..end - self.source_start + self.dest_start)
+    }
+}
+
+impl Map {
+    fn sort(&mut self) {
+        self.ranges.sort_by_key(|r| r.source_start);
+    }
+
+    fn map(&self, source: u64) -> u64 {
+        for map_range in &self.ranges {
+            if let Some(x) = map_range.map(source) {
+                return x;
+            }
+        }
+        source
+    }
+
+    /// Map every value in `sources` at once; like `map`, the first range to cover a value wins.
+    fn map_set(&self, sources: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let mut unmapped = sources.clone();
+        let mut mapped = IntervalSet::new();
+        for range in &self.ranges {
+            let covered = unmapped.intersection(&range.source_range().into());
+            for r in covered.iter() {
+                mapped.insert(range.map_range(&r).unwrap());
+            }
+            unmapped = unmapped.difference(&covered);
+        }
+        mapped.union(&unmapped)
+    }
+}
+
+/// Parse line `n` as a map range that stays within `u64`.
+fn parse_map_range(n: usize, line: &str) -> Result<MapRange> {
+    let r = parse_line(n, line, map_range)?;
+    if r.source_start.checked_add(r.length).is_none() || r.dest_start.checked_add(r.length).is_none() {
+        return Err(AocError::puzzle(format!("map range on line {n} runs past {}", u64::MAX)));
+    }
+    Ok(r)
+}
+
+fn map_range(input: &mut &str) -> PResult<MapRange> {
+    let dest_start = number.context(expected("destination start")).parse_next(input)?;
+    let _ = literal(" ").parse_next(input)?;
+    let source_start = number.context(expected("source start")).parse_next(input)?;
+    let _ = literal(" ").parse_next(input)?;
+    let length = number.context(expected("range length")).parse_next(input)?;
+    Ok(MapRange { dest_start, source_start, length })
+}
+
+fn map_header(input: &mut &str) -> PResult<Map> {
+    let from = map_source(input)?;
+    let _ = literal("-to-").context(StrContext::Label("after source category")).parse_next(input)?;
+    let to = map_source(input)?;
+    let _ = literal(" map:").context(StrContext::Label("after destination category")).parse_next(input)?;
+    Ok(Map { from, to, ranges: Vec::new() })
+}
+
+fn map_source(input: &mut &str) -> PResult<String> {
+    take_while(0.., |c:char| c != '-' && c != ' ')
+        .map(String::from)
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_map() {
+        let mut input = "humidity-to-location map:";
+
+        let g = map_header.parse_next(&mut input).unwrap();
+        assert_eq!(&g.from, "humidity");
+        assert_eq!(&g.to, "location");
+    }
+
+    #[test]
+    fn test_seeds() {
+        let mut input = "seeds: 79 14 55 13";
+
+        let s = seeds.parse_next(&mut input).unwrap();
+        assert_eq!(s.len(), 4);
+    }
+
+    #[test]
+    fn test_map_range() {
+        let range = MapRange { dest_start: 52, source_start: 50, length: 48 };
+        assert_eq!(range.map(50), Some(52));
+        assert_eq!(range.map(48), None);
+    }
+
+    #[test]
+    fn test_map_range_check_extreme() {
+        let range = MapRange { dest_start: 52, source_start: 50, length: 48 };
+        assert_eq!(range.map_range(&(0..10)), None);
+        assert_eq!(range.map_range(&(0..60)), Some(52..62));
+        assert_eq!(range.map_range(&(60..65)), Some(62..67));
+        assert_eq!(range.map_range(&(0..200)), Some(52..100));
+        assert_eq!(range.map_range(&(200..300)), None);
+    }
+
+    #[test]
+    fn test_map_set() {
+        // The sample's seed-to-soil map.
+        let map = Map {
+            from: "seed".into(),
+            to: "soil".into(),
+            ranges: vec![MapRange { dest_start: 50, source_start: 98, length: 2 },
+                         MapRange { dest_start: 52, source_start: 50, length: 48 }],
+        };
+        let seeds: IntervalSet<u64> = [79..93, 55..68].into_iter().collect();
+        assert_eq!(map.map_set(&seeds), [81..95, 57..70].into_iter().collect());
+        let seeds: IntervalSet<u64> = [40..60, 95..105].into_iter().collect();
+        assert_eq!(map.map_set(&seeds), [40..50, 50..52, 52..62, 97..100, 100..105].into_iter().collect());
+    }
+
+    #[test]
+    fn test_map_display() {
+        let input = "seed-to-soil map:\n50 98 2\n52 50 48";
+        let map = input.parse::<Map>().unwrap();
+        assert_eq!(map.ranges[0].source_start, 50);
+        assert_eq!(map.to_string(), "seed-to-soil map:\n52 50 48\n50 98 2");
+    }
+
+    fn map() -> impl Strategy<Value = Map> {
+        let range = (0..u32::MAX as u64, 0..u32::MAX as u64, 0..u32::MAX as u64)
+            .prop_map(|(dest_start, source_start, length)| MapRange { dest_start, source_start, length });
+        ("[a-z]{1,10}", "[a-z]{1,10}", prop::collection::vec(range, 0..5))
+            .prop_map(|(from, to, ranges)| {
+                let mut map = Map { from, to, ranges };
+                map.sort();
+                map
+            })
+    }
+
+    #[test]
+    fn test_generate() {
+        // Splitting the seed ranges finds locations lower than any range's first seed.
+        for seed in 0..5 {
+            let almanac = Day5::parse(&Day5::generate(&mut StdRng::seed_from_u64(seed), Day5::SIZE)).unwrap();
+            assert!(Day5::part2(&almanac).unwrap() < Day5::part1(&almanac).unwrap(), "seed {seed}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn almanac_round_trip(seeds in prop::collection::vec(0..u32::MAX as u64, 1..8),
+                              maps in prop::collection::vec(map(), 0..5)) {
+            let almanac = Almanac { seeds, maps };
+            prop_assert_eq!(Day5::parse(&almanac.to_string()).unwrap(), almanac);
+        }
+    }
+}
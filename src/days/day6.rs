@@ -0,0 +1,171 @@
+use std::fmt;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use winnow::prelude::*;
+use crate::error::{AocError, Result};
+use crate::math;
+use crate::parse::{literal, number_list, multiple_space, parse_line};
+use crate::solution::Solution;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Race>;
+    type Output = u64;
+    /// Much more and the kerned race no longer fits in a `u64`.
+    const SIZE: usize = 4;
+
+    fn parse(input: &str) -> Result<Vec<Race>> {
+        let mut lines = input.lines();
+        let times = parse_line(1, lines.next().unwrap_or_default(), times)?;
+        let distances = parse_line(2, lines.next().unwrap_or_default(), distances)?;
+        if times.len() != distances.len() {
+            return Err(AocError::puzzle(format!("{} times but {} distances", times.len(), distances.len())));
+        }
+        Ok(std::iter::zip(times, distances).map(|(a, b)| Race { time: a, distance: b }).collect())
+    }
+
+    fn part1(races: &Vec<Race>) -> Result<u64> {
+        races.iter()
+            .try_fold(1u64, |accum, r| accum.checked_mul(r.ways_to_win()))
+            .ok_or_else(|| AocError::overflow("product of ways to win"))
+    }
+
+    fn part2(races: &Vec<Race>) -> Result<u64> {
+        Ok(kern(races)?.ways_to_win())
+    }
+
+    /// `size` races, each of which can be won.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let races: Vec<Race> = (0..size)
+            .map(|_| {
+                let time = rng.gen_range(7..100);
+                let best = (time / 2) * (time - time / 2);
+                Race { time, distance: rng.gen_range(0..best) }
+            })
+            .collect();
+        format!("{}\n", Races(&races))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+/// Races printed as the puzzle's sheet, one right-aligned column per race.
+pub struct Races<'a>(pub &'a [Race]);
+
+impl fmt::Display for Races<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths: Vec<usize> = self.0.iter()
+            .map(|r| r.time.to_string().len().max(r.distance.to_string().len()))
+            .collect();
+        write!(f, "Time:    ")?;
+        for (r, w) in self.0.iter().zip(&widths) {
+            write!(f, "  {:>w$}", r.time)?;
+        }
+        write!(f, "\nDistance:")?;
+        for (r, w) in self.0.iter().zip(&widths) {
+            write!(f, "  {:>w$}", r.distance)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Race {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Races(std::slice::from_ref(self)).fmt(f)
+    }
+}
+
+fn times(input: &mut &str) -> PResult<Vec<u64>> {
+    let _ = literal("Time:").parse_next(input)?;
+    multiple_space(input)?;
+    number_list(input)
+}
+
+fn distances(input: &mut &str) -> PResult<Vec<u64>> {
+    let _ = literal("Distance:").parse_next(input)?;
+    multiple_space(input)?;
+    number_list(input)
+}
+
+/// The paper had bad kerning: all the races are really one long race.
+fn kern(races: &[Race]) -> Result<Race> {
+    let join = |f: fn(&Race) -> u64| -> Result<u64> {
+        races.iter().map(|r| f(r).to_string()).collect::<String>().parse()
+            .map_err(|_| AocError::overflow("kerned race"))
+    };
+    Ok(Race { time: join(|r| r.time)?, distance: join(|r| r.distance)? })
+}
+
+impl Race {
+    fn ways_to_win(&self) -> u64 {
+        // Holding for h wins when h * (time - h) > distance, which is when
+        // |time - 2h| squared is below time^2 - 4 distance.
+        let time = u128::from(self.time);
+        let Some(disc) = (time * time).checked_sub(4 * u128::from(self.distance)).filter(|&d| d > 0) else {
+            return 0;
+        };
+        // |time - 2h| has the same parity as time, and each value but 0 comes from two holds.
+        let furthest = math::isqrt128(disc - 1) as u64;
+        if self.time.is_multiple_of(2) { 1 + furthest / 2 * 2 } else { furthest.div_ceil(2) * 2 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_ways_to_win() {
+        let race = Race { time: 7, distance: 9 };
+        assert_eq!(race.ways_to_win(), 4);
+        let race = Race { time: 15, distance: 40 };
+        assert_eq!(race.ways_to_win(), 8);
+        let race = Race { time: 30, distance: 200 };
+        assert_eq!(race.ways_to_win(), 9);
+    }
+
+    #[test]
+    fn test_ways_to_win_matches_search() {
+        for time in 0..40 {
+            for distance in 0..(time * time / 4 + 2) {
+                let race = Race { time, distance };
+                let search = (0..=time).filter(|hold| hold * (time - hold) > distance).count() as u64;
+                assert_eq!(race.ways_to_win(), search, "{race:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_kern() {
+        let races = vec![Race { time: 7, distance: 9 },
+                         Race { time: 15, distance: 40 },
+                         Race { time: 30, distance: 200 }];
+        let race = kern(&races).unwrap();
+        assert_eq!(race.time, 71530);
+        assert_eq!(race.distance, 940200);
+        assert_eq!(race.ways_to_win(), 71503);
+    }
+
+    #[test]
+    fn test_races_display() {
+        let races = vec![Race { time: 7, distance: 9 },
+                         Race { time: 15, distance: 40 },
+                         Race { time: 30, distance: 200 }];
+        assert_eq!(Races(&races).to_string(), "Time:      7  15   30\nDistance:  9  40  200");
+        assert_eq!(kern(&races).unwrap().to_string(), "Time:       71530\nDistance:  940200");
+    }
+
+    proptest! {
+        #[test]
+        fn races_round_trip(races in prop::collection::vec((0..100_000u64, 0..10_000_000u64), 1..6)) {
+            let races: Vec<Race> = races.into_iter().map(|(time, distance)| Race { time, distance }).collect();
+            prop_assert_eq!(Day6::parse(&Races(&races).to_string()).unwrap(), races);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use std::cmp::Ordering::{self, *};
+use std::collections::HashSet;
+use std::fmt;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use winnow::prelude::*;
+use winnow::token::*;
+use winnow::combinator::*;
+use crate::error::{AocError, Result};
+use crate::parse::{expected, lines, number, multiple_space, parse_line};
+use crate::solution::Solution;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Hand>;
+    type Output = u32;
+    const SIZE: usize = 1000;
+
+    fn parse(input: &str) -> Result<Vec<Hand>> {
+        lines(input, hand)
+    }
+
+    fn part1(hands: &Vec<Hand>) -> Result<u32> {
+        winnings(hands.clone()).ok_or_else(|| AocError::overflow("total winnings"))
+    }
+
+    fn part2(hands: &Vec<Hand>) -> Result<u32> {
+        winnings(hands.iter().map(Hand::with_jokers).collect()).ok_or_else(|| AocError::overflow("total winnings"))
+    }
+
+    /// `size` different hands, so the ranking doesn't depend on sort order.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut seen = HashSet::new();
+        let mut out = String::new();
+        while seen.len() < size.min(13usize.pow(5)) {
+            // Draw from a few ranks at a time so pairs and better show up.
+            let ranks: Vec<u8> = (0..rng.gen_range(1..=5)).map(|_| rng.gen_range(2..=14)).collect();
+            let cards: Vec<u8> = (0..5).map(|_| *ranks.choose(rng).unwrap()).collect();
+            if seen.insert(cards.clone()) {
+                out.push_str(&format!("{}\n", Hand { cards, bid: rng.gen_range(1..=1000) }));
+            }
+        }
+        out
+    }
+}
+
+const JACK: u8 = 11;
+const JOKER: u8 = 1;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Hand {
+    cards: Vec<u8>,
+    bid: u32,
+}
+
+impl std::str::FromStr for Hand {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        parse_line(1, input, hand)
+    }
+}
+
+/// Jokers print as `J`, the card they came from.
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &card in &self.cards {
+            let c = match card {
+                10 => 'T',
+                JACK | JOKER => 'J',
+                12 => 'Q',
+                13 => 'K',
+                14 => 'A',
+                n => char::from(b'0' + n),
+            };
+            write!(f, "{c}")?;
+        }
+        write!(f, " {}", self.bid)
+    }
+}
+
+impl Hand {
+    /// Jacks become jokers: wild for the kind, weakest for ties.
+    fn with_jokers(&self) -> Hand {
+        let cards = self.cards.iter().map(|&c| if c == JACK { JOKER } else { c }).collect();
+        Hand { cards, bid: self.bid }
+    }
+
+    fn kind(&self) -> u8 {
+        let mut kinds : [u8; 15] =  [0; 15];
+        for card in &self.cards {
+            kinds[*card as usize] += 1;
+        }
+        let jokers = kinds[JOKER as usize];
+        kinds[JOKER as usize] = 0;
+        kinds.sort();
+        match (kinds[14] + jokers, kinds[13]) {
+            (5, _) => 7,
+            (4, _) => 6,
+            (3, 2) => 5,
+            (3, _) => 4,
+            (2, 2) => 3,
+            (2, _) => 2,
+            _ => 1,
+        }
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Hand) -> Ordering {
+        let h = self.kind();
+        let o = other.kind();
+        if h < o {
+            Less
+        } else if h > o {
+            Greater
+        } else {
+            for i in 0..5 {
+                if self.cards[i] < other.cards[i] {
+                    return Less;
+                } else if self.cards[i] > other.cards[i] {
+                    return Greater;
+                }
+            }
+            Equal
+        }
+    }
+}
+
+impl PartialOrd<Hand> for Hand {
+    fn partial_cmp(&self, other: &Hand) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn winnings(mut hands: Vec<Hand>) -> Option<u32> {
+    hands.sort();
+    let mut accum: u32 = 0;
+    for (i, hand) in hands.iter().enumerate() {
+        let rank = u32::try_from(i + 1).ok()?;
+        accum = accum.checked_add(rank.checked_mul(hand.bid)?)?;
+    }
+    Some(accum)
+}
+
+fn cards(input: &mut &str) -> PResult<Vec<u8>> {
+    repeat(5, alt(('T'.value(10),
+         'J'.value(JACK),
+         'Q'.value(12),
+         'K'.value(13),
+         'A'.value(14),
+         one_of('2'..='9').map(|c: char| c as u8 - b'0'))).context(expected("a card 2-9, T, J, Q, K or A")))
+        .parse_next(input)
+}
+
+fn hand(input: &mut &str) -> PResult<Hand> {
+    let cards = cards(input)?;
+    multiple_space(input)?;
+    let bid = number.context(expected("bid")).parse_next(input)?;
+    Ok(Hand { cards, bid })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_hand_parse() {
+        let mut input = "KQJT9 1";
+
+        let h = hand.parse_next(&mut input).unwrap();
+        assert_eq!(h.cards[0], 13);
+        assert_eq!(h.cards[1], 12);
+        assert_eq!(h.cards[2], 11);
+        assert_eq!(h.cards[3], 10);
+        assert_eq!(h.cards[4], 9);
+        assert_eq!(h.kind(), 1);
+        let h = h.with_jokers();
+        assert_eq!(h.cards[2], 1);
+        assert_eq!(h.kind(), 2);
+    }
+
+    #[test]
+    fn test_hand_kind2() {
+        let mut input = "KKJT9 1";
+
+        let h = hand.parse_next(&mut input).unwrap();
+        assert_eq!(h.kind(), 2);
+        assert_eq!(h.with_jokers().kind(), 4);
+    }
+
+    #[test]
+    fn test_hand_kind3() {
+        let mut input = "KKJJ9 1";
+
+        let h = hand.parse_next(&mut input).unwrap();
+        assert_eq!(h.kind(), 3);
+        assert_eq!(h.with_jokers().kind(), 6);
+    }
+
+    #[test]
+    fn test_all_jokers() {
+        let mut input = "JJJJJ 1";
+
+        let h = hand.parse_next(&mut input).unwrap();
+        assert_eq!(h.kind(), 7);
+        assert_eq!(h.with_jokers().kind(), 7);
+    }
+
+    #[test]
+    fn test_hand_display() {
+        let h = "KTJJT 220".parse::<Hand>().unwrap();
+        assert_eq!(h.to_string(), "KTJJT 220");
+        assert_eq!(h.with_jokers().to_string(), "KTJJT 220");
+    }
+
+    proptest! {
+        #[test]
+        fn hand_round_trip(cards in prop::collection::vec(2..=14u8, 5), bid in 0..10_000u32) {
+            let h = Hand { cards, bid };
+            prop_assert_eq!(h.to_string().parse::<Hand>().unwrap(), h);
+        }
+    }
+}
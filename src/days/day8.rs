@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use tracing::debug;
+use winnow::prelude::*;
+use winnow::combinator::*;
+use crate::error::{AocError, Result};
+use crate::graph::{find_cycle, Cycle, Graph, Labels};
+use crate::math;
+use winnow::error::{StrContext, StrContextValue};
+use crate::parse::{expected, label, literal, parse_line};
+use crate::solution::Solution;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+    type Output = u64;
+    const SIZE: usize = 50;
+
+    fn parse(input: &str) -> Result<Network> {
+        let mut lines = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+        let dirs = parse_line(1, lines.next().unwrap_or_default().1, directions)?;
+        let _ = lines.next();
+        let mut nodes: HashMap<String, Node<String>> = HashMap::new();
+        for (n, l) in lines {
+            let node = parse_line(n, l, node)?;
+            nodes.insert(node.name.clone(), node);
+        }
+        Ok(Network { dirs, nodes })
+    }
+
+    fn part1(network: &Network) -> Result<u64> {
+        let walk = Walk::new(network);
+        let start = walk.labels.get("AAA").ok_or_else(|| AocError::puzzle("no node AAA"))?;
+        let (_, ends) = walk.ends(start, |n| n == "ZZZ")?;
+        ends.first().copied().ok_or_else(never_ends)
+    }
+
+    fn part2(network: &Network) -> Result<u64> {
+        // Each ghost runs its own loop; when each reaches an end at a steady rate,
+        // they all line up at the least common multiple.
+        let walk = Walk::new(network);
+        let mut starts: Vec<&String> = network.nodes.keys().filter(|n| n.ends_with('A')).collect();
+        starts.sort();
+        let periods = starts.into_iter()
+            .map(|start| walk.period(walk.labels.get(start.as_str()).unwrap()))
+            .collect::<Result<Vec<u64>>>()?;
+        math::lcm_all(periods).ok_or_else(|| AocError::overflow("steps for every ghost"))
+    }
+
+    /// `size` directions and six ghosts whose walks from `..A` reach `..Z` every
+    /// `size` times a prime, a different one each, drawn from 3 to 23; the first
+    /// ghost starts at `AAA`.
+    ///
+    /// Every node on a ghost's loop is only ever reached at the same point in the
+    /// directions. Turning the way they say goes on round the loop; turning the
+    /// other way leads to a decoy that goes back to the loop's start.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        const PRIMES: [usize; 8] = [3, 5, 7, 11, 13, 17, 19, 23];
+        let m = size.max(1);
+        let dirs: Vec<Dir> = (0..m).map(|_| if rng.gen() { Dir::Left } else { Dir::Right }).collect();
+        let cycles: Vec<usize> = PRIMES.choose_multiple(rng, 6).copied().collect();
+        let needed: usize = cycles.iter().map(|c| 2 * c * m).sum();
+        let mut width = 3;
+        while 36usize.pow(width - 1) * 34 < 2 * needed {
+            width += 1;
+        }
+        let mut used: HashSet<String> = ["AAA", "ZZZ"].map(String::from).into();
+        let mut nodes = HashMap::new();
+        let mut add = |name: &String, on: &String, off: &String, dir: &Dir| {
+            let directions = match dir {
+                Dir::Left => (on.clone(), off.clone()),
+                Dir::Right => (off.clone(), on.clone()),
+            };
+            nodes.insert(name.clone(), Node { name: name.clone(), directions });
+        };
+        for (g, &c) in cycles.iter().enumerate() {
+            let (start, end) = if g == 0 {
+                ("AAA".to_string(), "ZZZ".to_string())
+            } else {
+                (fresh_label(rng, &mut used, width, Some('A')), fresh_label(rng, &mut used, width, Some('Z')))
+            };
+            // The loop's nodes after `start`, one for each step before coming back to
+            // `end`, and a decoy beside each; `start` and `end` share the first decoy.
+            let steps: Vec<String> = (1..c * m).map(|_| fresh_label(rng, &mut used, width, None)).collect();
+            let decoys: Vec<String> = (0..c * m).map(|_| fresh_label(rng, &mut used, width, None)).collect();
+            add(&start, &steps[0], &decoys[0], &dirs[0]);
+            add(&end, &steps[0], &decoys[0], &dirs[0]);
+            for (j, step) in steps.iter().enumerate() {
+                let next = steps.get(j + 1).unwrap_or(&end);
+                add(step, next, &decoys[j + 1], &dirs[(j + 1) % m]);
+            }
+            for decoy in &decoys {
+                add(decoy, &steps[0], &steps[0], &Dir::Left);
+            }
+        }
+        format!("{}\n", Network { dirs, nodes })
+    }
+}
+
+/// A label of `width` not in `used`, ending in `last` if given and never in `A` or `Z` otherwise.
+fn fresh_label(rng: &mut StdRng, used: &mut HashSet<String>, width: u32, last: Option<char>) -> String {
+    const CHARS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    const NOT_A_OR_Z: &[u8] = b"0123456789BCDEFGHIJKLMNOPQRSTUVWXY";
+    loop {
+        let mut label: String = (1..width).map(|_| char::from(*CHARS.choose(rng).unwrap())).collect();
+        label.push(last.unwrap_or_else(|| char::from(*NOT_A_OR_Z.choose(rng).unwrap())));
+        if used.insert(label.clone()) {
+            return label;
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Network {
+    dirs: Vec<Dir>,
+    nodes: HashMap<String, Node<String>>,
+}
+
+/// Nodes print sorted by name.
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for d in &self.dirs {
+            write!(f, "{d}")?;
+        }
+        writeln!(f)?;
+        let mut names: Vec<&String> = self.nodes.keys().collect();
+        names.sort();
+        for name in names {
+            write!(f, "\n{}", self.nodes[name])?;
+        }
+        Ok(())
+    }
+}
+
+/// The network with its nodes numbered for walking. A walker is at a node, some
+/// way through the directions; each node's edges are to its left then right node.
+struct Walk<'a> {
+    dirs: &'a [Dir],
+    graph: Graph,
+    labels: Labels<&'a str>,
+}
+
+type Walker = (usize, usize);
+
+impl<'a> Walk<'a> {
+    fn new(network: &'a Network) -> Walk<'a> {
+        let mut labels = Labels::new();
+        let mut edges = Vec::new();
+        let mut names: Vec<&String> = network.nodes.keys().collect();
+        names.sort();
+        for name in names {
+            let (left, right) = &network.nodes[name].directions;
+            let from = labels.id(name.as_str());
+            edges.push((from, labels.id(left.as_str()), 1));
+            edges.push((from, labels.id(right.as_str()), 1));
+        }
+        Walk { dirs: &network.dirs, graph: Graph::new(labels.len(), edges), labels }
+    }
+
+    fn next(&self, (node, i): Walker) -> Walker {
+        let side = match self.dirs[i] {
+            Dir::Left => 0,
+            Dir::Right => 1,
+        };
+        (self.graph.neighbours(node)[side], (i + 1) % self.dirs.len())
+    }
+
+    /// The cycle a walker from `start` falls into, and the steps at which it's on an
+    /// end node before the cycle first comes back around.
+    fn ends<E: Fn(&str) -> bool>(&self, start: usize, end: E) -> Result<(Cycle, Vec<u64>)> {
+        let reachable = self.graph.bfs(start);
+        if let Some(v) = (0..self.graph.len()).find(|&v| reachable[v].is_some() && self.graph.neighbours(v).is_empty()) {
+            return Err(AocError::puzzle(format!("the path can lead to node {}, which isn't defined", self.labels.name(v))));
+        }
+        let cycle = find_cycle((start, 0), |&w| self.next(w));
+        let mut walker = (start, 0);
+        let mut ends = Vec::new();
+        for step in 0..cycle.tail + cycle.length {
+            if end(self.labels.name(walker.0)) {
+                ends.push(step);
+            }
+            walker = self.next(walker);
+        }
+        Ok((cycle, ends))
+    }
+
+    /// How many steps apart a ghost from `start` is on `..Z` nodes, which has to be
+    /// steady from the start for the least common multiple to work.
+    fn period(&self, start: usize) -> Result<u64> {
+        let (cycle, ends) = self.ends(start, |n| n.ends_with('Z'))?;
+        let &first = ends.first().ok_or_else(never_ends)?;
+        let steady = first > 0 && first >= cycle.tail && cycle.length % first == 0
+            && ends.iter().enumerate().all(|(k, &step)| step == (k as u64 + 1) * first)
+            && ends.len() as u64 == (cycle.tail + cycle.length - 1) / first;
+        if !steady {
+            return Err(AocError::puzzle(format!("the ghost from {} doesn't reach `..Z` nodes at a steady rate",
+                                                self.labels.name(start))));
+        }
+        debug!(start = self.labels.name(start), tail = cycle.tail, length = cycle.length, period = first, "ghost");
+        Ok(first)
+    }
+}
+
+fn never_ends() -> AocError {
+    AocError::puzzle("the path never reaches an end node")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Dir {
+    Left,
+    Right
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node<T> {
+    name: T,
+    directions: (T, T)
+}
+
+impl fmt::Display for Dir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self { Dir::Left => 'L', Dir::Right => 'R' })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Node<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = ({}, {})", self.name, self.directions.0, self.directions.1)
+    }
+}
+
+
+fn dir(input: &mut &str) -> PResult<Dir> {
+    alt(('L'.value(Dir::Left),
+         'R'.value(Dir::Right)))
+        .context(StrContext::Expected(StrContextValue::CharLiteral('L')))
+        .context(StrContext::Expected(StrContextValue::CharLiteral('R')))
+        .parse_next(input)
+}
+
+fn directions(input: &mut &str) -> PResult<Vec<Dir>> {
+    repeat(1.., dir).parse_next(input)
+}
+
+fn node(input: &mut &str) -> PResult<Node<String>> {
+    let name = label.context(expected("node name")).parse_next(input)?;
+    let _ = literal(" = (").parse_next(input)?;
+    let left = label.context(expected("left node")).parse_next(input)?;
+    let _ = literal(", ").parse_next(input)?;
+    let right = label.context(expected("right node")).parse_next(input)?;
+    let _ = literal(")").parse_next(input)?;
+    Ok(Node { name, directions: (left, right) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_node() {
+        let mut input = "AAA = (BBB, CCC)";
+
+        let n = node.parse_next(&mut input).unwrap();
+        assert_eq!(n.name, "AAA".to_string());
+        assert_eq!(n.directions.0, "BBB".to_string());
+        assert_eq!(n.directions.1, "CCC".to_string());
+    }
+
+    #[test]
+    fn test_node_display() {
+        let input = "11A = (11B, XXX)";
+        assert_eq!(node.parse(input).unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn test_walk_errors() {
+        let network = Day8::parse("L\n\nAAA = (BBB, BBB)\n").unwrap();
+        assert!(Day8::part1(&network).unwrap_err().to_string().contains("node BBB, which isn't defined"));
+        // On `11Z` after 1 step, then every 3.
+        let network = Day8::parse("L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11C, 11C)\n11C = (11Z, 11Z)\n").unwrap();
+        assert!(Day8::part2(&network).unwrap_err().to_string().contains("steady rate"));
+    }
+
+    #[test]
+    fn test_generate() {
+        let answers = |seed| {
+            let network = Day8::parse(&Day8::generate(&mut StdRng::seed_from_u64(seed), 4)).unwrap();
+            (Day8::part1(&network).unwrap(), Day8::part2(&network).unwrap())
+        };
+        let (part1, part2) = answers(1);
+        assert_eq!(part1 % 4, 0);
+        assert_eq!(part2 % part1, 0);
+        assert_ne!(answers(2).0, part1);
+    }
+
+    proptest! {
+        #[test]
+        fn network_round_trip(dirs in prop::collection::vec(prop::bool::ANY, 1..20),
+                              nodes in prop::collection::vec(("[A-Z0-9]{3}", "[A-Z0-9]{3}", "[A-Z0-9]{3}"), 0..10)) {
+            let dirs = dirs.into_iter().map(|left| if left { Dir::Left } else { Dir::Right }).collect();
+            let nodes = nodes.into_iter()
+                .map(|(name, left, right)| (name.clone(), Node { name, directions: (left, right) }))
+                .collect();
+            let network = Network { dirs, nodes };
+            prop_assert_eq!(Day8::parse(&network.to_string()).unwrap(), network);
+        }
+    }
+}
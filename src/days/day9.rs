@@ -0,0 +1,112 @@
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use tracing::debug;
+use crate::error::{AocError, Result};
+use crate::parse::{lines, signed_number_list};
+use crate::solution::Solution;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i64>>;
+    type Output = i64;
+    const SIZE: usize = 200;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>> {
+        lines(input, signed_number_list)
+    }
+
+    fn part1(histories: &Vec<Vec<i64>>) -> Result<i64> {
+        sum(histories.iter().map(|h| extrapolate(h.clone())))
+    }
+
+    fn part2(histories: &Vec<Vec<i64>>) -> Result<i64> {
+        sum(histories.iter().map(|h| extrapolate(h.iter().rev().copied().collect())))
+    }
+
+    /// `size` histories of 21 values from polynomials of degree up to 8.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut out = String::new();
+        for _ in 0..size {
+            let coefficients: Vec<i64> = (0..=rng.gen_range(0..=8)).map(|_| rng.gen_range(-9..=9)).collect();
+            let values: Vec<String> = (0..21i64)
+                .map(|x| coefficients.iter().rev().fold(0, |accum, c| accum * x + c).to_string())
+                .collect();
+            out.push_str(&values.join(" "));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+fn sum(values: impl Iterator<Item = Result<i64>>) -> Result<i64> {
+    values.into_iter().try_fold(0i64, |accum, x| {
+        accum.checked_add(x?).ok_or_else(|| AocError::overflow("sum of extrapolated values"))
+    })
+}
+
+fn extrapolate(numbers: Vec<i64>) -> Result<i64> {
+    let mut game = Game { entries: vec![numbers] };
+    while ! game.next()? {
+    }
+    game.fill_next()
+}
+
+#[derive(Debug)]
+struct Game {
+    entries: Vec<Vec<i64>>
+}
+
+impl Game {
+    fn next(&mut self) -> Result<bool> {
+        let last = self.entries.last().ok_or_else(empty)?;
+        debug!(row = ?last, "differences");
+        let l = last.windows(2)
+            .map(|x| x[1].checked_sub(x[0]))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| AocError::overflow("differences"))?;
+        // println!("{:?}", &l);
+        let is_done = l.iter().all(|x| x == &0);
+        self.entries.push(l);
+        Ok(is_done)
+    }
+
+    fn fill_next(&mut self) -> Result<i64> {
+        let n = self.entries.len();
+        self.entries.last_mut().ok_or_else(empty)?.push(0);
+        for i in 1..n {
+            debug!(i, n, "extending row");
+            let l = self.entries[n - 1 - (i - 1)].last().copied().ok_or_else(empty)?;
+            let upper = &mut self.entries[n - 1 - i];
+            let u = upper.last().copied().ok_or_else(empty)?;
+            // u - u* == l
+            let u_p = u.checked_add(l).ok_or_else(|| AocError::overflow("next value"))?;
+            upper.push(u_p);
+        }
+        self.entries[0].last().copied().ok_or_else(empty)
+    }
+}
+
+fn empty() -> AocError {
+    AocError::puzzle("empty history")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolate() {
+        assert_eq!(extrapolate(vec![0, 3, 6, 9, 12, 15]).unwrap(), 18);
+        assert_eq!(extrapolate(vec![15, 12, 9, 6, 3, 0]).unwrap(), -3);
+        assert!(extrapolate(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_long_history() {
+        // Long enough that weighting every value, as Lagrange interpolation does, overflows.
+        assert_eq!(extrapolate(vec![1; 140]).unwrap(), 1);
+        assert_eq!(extrapolate((0..500).map(|x| 3 * x - 7).collect()).unwrap(), 3 * 500 - 7);
+        assert_eq!(extrapolate((0..300).map(|x| x * x).collect()).unwrap(), 300 * 300);
+    }
+}
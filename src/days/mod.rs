@@ -0,0 +1,66 @@
+use rand::rngs::StdRng;
+use crate::bench::{self, Stats};
+use crate::error::Result;
+use crate::solution::{Part, Solution};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+
+pub struct Day {
+    pub number: u32,
+    pub solve: fn(&str, Part) -> Result<String>,
+    pub solve_both: fn(&str) -> Result<[Result<String>; 2]>,
+    pub generate: fn(&mut StdRng, usize) -> String,
+    /// Default `size` for `generate`.
+    pub size: usize,
+    /// Timings of parse, part 1 and part 2 over some number of iterations.
+    pub bench: fn(&str, usize) -> Result<[Stats; 3]>,
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u32) -> Day {
+        Day { number, solve: S::solve, solve_both: S::solve_both, generate: S::generate, size: S::SIZE, bench: bench::time::<S> }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(1),
+    Day::new::<day2::Day2>(2),
+    Day::new::<day3::Day3>(3),
+    Day::new::<day4::Day4>(4),
+    Day::new::<day5::Day5>(5),
+    Day::new::<day6::Day6>(6),
+    Day::new::<day7::Day7>(7),
+    Day::new::<day8::Day8>(8),
+    Day::new::<day9::Day9>(9),
+    Day::new::<day10::Day10>(10),
+];
+
+pub fn get(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for day in DAYS {
+            let text = (day.generate)(&mut StdRng::seed_from_u64(1), 10);
+            assert_eq!(text, (day.generate)(&mut StdRng::seed_from_u64(1), 10), "day {}", day.number);
+            if let Err(e) = (day.solve)(&text, Part::One) {
+                panic!("day {}: {e}\n{text}", day.number);
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io { path: PathBuf, source: io::Error },
+    /// Input that doesn't match the puzzle's format; line and column are 1-based.
+    Parse { file: Option<PathBuf>, line: usize, column: usize, text: String, message: String },
+    /// Input that parses but breaks something the puzzle promised.
+    Puzzle(String),
+    Overflow(String),
+}
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+impl AocError {
+    /// A parse error in `text`, line `line` of the input, at byte `offset`.
+    pub fn parse(line: usize, text: &str, offset: usize, message: impl Into<String>) -> AocError {
+        let column = text.get(..offset).map_or(offset, |s| s.chars().count()) + 1;
+        AocError::Parse { file: None, line, column, text: text.into(), message: message.into() }
+    }
+
+    /// Note which file a parse error came from.
+    pub fn in_file(self, path: impl Into<PathBuf>) -> AocError {
+        match self {
+            AocError::Parse { line, column, text, message, .. } =>
+                AocError::Parse { file: Some(path.into()), line, column, text, message },
+            e => e,
+        }
+    }
+
+    pub fn puzzle(message: impl Into<String>) -> AocError {
+        AocError::Puzzle(message.into())
+    }
+
+    pub fn overflow(what: impl Into<String>) -> AocError {
+        AocError::Overflow(what.into())
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => write!(f, "cannot read {}: {source}", path.display()),
+            AocError::Parse { file, line, column, text, message } => {
+                match file {
+                    Some(file) => writeln!(f, "parse error at {}:{line}:{column}: {message}", file.display())?,
+                    None => writeln!(f, "parse error at line {line}, column {column}: {message}")?,
+                }
+                let gutter = " ".repeat(line.to_string().len());
+                writeln!(f, "{gutter} |")?;
+                writeln!(f, "{line} | {text}")?;
+                write!(f, "{gutter} | {}^", " ".repeat(column - 1))
+            }
+            AocError::Puzzle(message) => write!(f, "invalid puzzle input: {message}"),
+            AocError::Overflow(what) => write!(f, "overflow computing {what}"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_caret() {
+        let e = AocError::parse(12, "Card 1: 41 x", 10, "expected ' |' after winning numbers");
+        assert_eq!(e.to_string(), "parse error at line 12, column 11: expected ' |' after winning numbers\n   |\n12 | Card 1: 41 x\n   |           ^");
+        let e = e.in_file("day4.txt");
+        assert!(e.to_string().starts_with("parse error at day4.txt:12:11: "));
+    }
+}
@@ -0,0 +1,196 @@
+//! Points, compass directions and polygons on the integer plane, with `y` growing down the page.
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::math::gcd;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+pub const ORIGIN: Point = Point::new(0, 0);
+pub const NORTH: Point = Point::new(0, -1);
+pub const EAST: Point = Point::new(1, 0);
+pub const SOUTH: Point = Point::new(0, 1);
+pub const WEST: Point = Point::new(-1, 0);
+
+/// The four directions, clockwise from north.
+pub const COMPASS4: [Point; 4] = [NORTH, EAST, SOUTH, WEST];
+/// The eight directions including diagonals, clockwise from north.
+pub const COMPASS8: [Point; 8] = [
+    NORTH, Point::new(1, -1), EAST, Point::new(1, 1), SOUTH, Point::new(-1, 1), WEST, Point::new(-1, -1),
+];
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    /// This direction turned a quarter anticlockwise, as seen on the page.
+    pub fn left(self) -> Point {
+        Point::new(self.y, -self.x)
+    }
+
+    /// This direction turned a quarter clockwise, as seen on the page.
+    pub fn right(self) -> Point {
+        Point::new(-self.y, self.x)
+    }
+
+    pub fn manhattan(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Distance when diagonal steps count as one, like a king in chess.
+    pub fn chebyshev(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, k: i32) -> Point {
+        Point::new(self.x * k, self.y * k)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+/// The smallest box holding some points; both corners are inside it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn of(points: impl IntoIterator<Item = Point>) -> Option<Bounds> {
+        points.into_iter().fold(None, |bounds, p| match bounds {
+            None => Some(Bounds { min: p, max: p }),
+            Some(Bounds { min, max }) => Some(Bounds {
+                min: Point::new(min.x.min(p.x), min.y.min(p.y)),
+                max: Point::new(max.x.max(p.x), max.y.max(p.y)),
+            }),
+        })
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn width(&self) -> u32 {
+        self.min.x.abs_diff(self.max.x) + 1
+    }
+
+    pub fn height(&self) -> u32 {
+        self.min.y.abs_diff(self.max.y) + 1
+    }
+}
+
+/// Twice the area of the polygon through `vertices` in order, by the shoelace formula;
+/// positive when they run clockwise on the page.
+pub fn double_area(vertices: &[Point]) -> i64 {
+    let pairs = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    pairs.map(|(a, b)| a.x as i64 * b.y as i64 - b.x as i64 * a.y as i64).sum()
+}
+
+/// How many lattice points lie on the edges of the polygon through `vertices`.
+pub fn boundary_points(vertices: &[Point]) -> u64 {
+    let pairs = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    pairs.map(|(a, b)| gcd(a.x.abs_diff(b.x).into(), a.y.abs_diff(b.y).into())).sum()
+}
+
+/// How many lattice points lie strictly inside the polygon through `vertices`, by Pick's theorem.
+pub fn interior_points(vertices: &[Point]) -> u64 {
+    if vertices.is_empty() {
+        return 0;
+    }
+    // A = i + b/2 - 1, so 2i = 2A - b + 2.
+    ((double_area(vertices).unsigned_abs() + 2).saturating_sub(boundary_points(vertices))) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(NORTH.right(), EAST);
+        assert_eq!(NORTH.left(), WEST);
+        assert_eq!(COMPASS4.map(|d| d.right().right()), COMPASS4.map(|d| -d));
+        assert_eq!(Point::new(3, 4) + SOUTH * 2 - EAST, Point::new(2, 6));
+    }
+
+    #[test]
+    fn test_distance() {
+        let (a, b) = (Point::new(1, -2), Point::new(-3, 5));
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert!(COMPASS8.iter().all(|&d| ORIGIN.chebyshev(d) == 1));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let bounds = Bounds::of([Point::new(2, 3), Point::new(-1, 7), Point::new(0, 0)]).unwrap();
+        assert_eq!(bounds, Bounds { min: Point::new(-1, 0), max: Point::new(2, 7) });
+        assert_eq!((bounds.width(), bounds.height()), (4, 8));
+        assert!(bounds.contains(Point::new(0, 7)));
+        assert!(!bounds.contains(Point::new(3, 7)));
+        assert_eq!(Bounds::of([]), None);
+    }
+
+    #[test]
+    fn test_polygon() {
+        // A 4 by 3 rectangle, clockwise on the page.
+        let rect = [Point::new(0, 0), Point::new(4, 0), Point::new(4, 3), Point::new(0, 3)];
+        assert_eq!(double_area(&rect), 24);
+        let mut reversed = rect;
+        reversed.reverse();
+        assert_eq!(double_area(&reversed), -24);
+        assert_eq!(boundary_points(&rect), 14);
+        assert_eq!(interior_points(&rect), 3 * 2);
+        assert_eq!(interior_points(&[]), 0);
+    }
+}
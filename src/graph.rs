@@ -0,0 +1,313 @@
+//! Directed graphs over numbered nodes, and the usual ways of walking them.
+use std::borrow::Borrow;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A directed graph with weighted edges, stored compactly: the edges out of node `v`
+/// are `targets[offsets[v]..offsets[v + 1]]`, in the order they were given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<u64>,
+}
+
+impl Graph {
+    /// A graph of nodes `0..n` with edges `(from, to, weight)`.
+    ///
+    /// Panics if an edge refers to a node that isn't below `n`.
+    pub fn new(n: usize, edges: impl IntoIterator<Item = (usize, usize, u64)>) -> Graph {
+        let edges: Vec<(usize, usize, u64)> = edges.into_iter().collect();
+        let mut offsets = vec![0; n + 1];
+        for &(from, to, _) in &edges {
+            assert!(from < n && to < n, "edge {from} -> {to} outside a graph of {n} nodes");
+            offsets[from + 1] += 1;
+        }
+        for v in 0..n {
+            offsets[v + 1] += offsets[v];
+        }
+        let mut next = offsets.clone();
+        let mut targets = vec![0; edges.len()];
+        let mut weights = vec![0; edges.len()];
+        for (from, to, weight) in edges {
+            targets[next[from]] = to;
+            weights[next[from]] = weight;
+            next[from] += 1;
+        }
+        Graph { offsets, targets, weights }
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The nodes `v` has edges to, in the order they were given.
+    pub fn neighbours(&self, v: usize) -> &[usize] {
+        &self.targets[self.offsets[v]..self.offsets[v + 1]]
+    }
+
+    /// The edges out of `v` as `(to, weight)`.
+    pub fn edges(&self, v: usize) -> impl Iterator<Item = (usize, u64)> + '_ {
+        let range = self.offsets[v]..self.offsets[v + 1];
+        self.targets[range.clone()].iter().copied().zip(self.weights[range].iter().copied())
+    }
+
+    /// The same nodes with every edge turned around.
+    pub fn reversed(&self) -> Graph {
+        let edges = (0..self.len()).flat_map(|v| self.edges(v).map(move |(to, w)| (to, v, w)));
+        Graph::new(self.len(), edges)
+    }
+
+    /// How many edges each node is from `start`, ignoring weights; `None` if unreachable.
+    pub fn bfs(&self, start: usize) -> Vec<Option<usize>> {
+        let mut dist = vec![None; self.len()];
+        dist[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(v) = queue.pop_front() {
+            let d = dist[v].map(|d| d + 1);
+            for &to in self.neighbours(v) {
+                if dist[to].is_none() {
+                    dist[to] = d;
+                    queue.push_back(to);
+                }
+            }
+        }
+        dist
+    }
+
+    /// The nodes reachable from `start` in depth-first preorder.
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+        while let Some(v) = stack.pop() {
+            if std::mem::replace(&mut seen[v], true) {
+                continue;
+            }
+            order.push(v);
+            // Pushed in reverse so the first neighbour is visited first.
+            stack.extend(self.neighbours(v).iter().rev().filter(|&&to| !seen[to]));
+        }
+        order
+    }
+
+    /// The length of the lightest path from `start` to each node; `None` if unreachable,
+    /// or if every path there is too heavy for a `u64`.
+    pub fn dijkstra(&self, start: usize) -> Vec<Option<u64>> {
+        let mut dist = vec![None; self.len()];
+        let mut heap = BinaryHeap::from([Reverse((0u64, start))]);
+        while let Some(Reverse((d, v))) = heap.pop() {
+            if dist[v].is_some() {
+                continue;
+            }
+            dist[v] = Some(d);
+            for (to, w) in self.edges(v) {
+                if let (None, Some(d)) = (dist[to], d.checked_add(w)) {
+                    heap.push(Reverse((d, to)));
+                }
+            }
+        }
+        dist
+    }
+
+    /// The strongly connected components, in topological order: edges from a
+    /// component only lead to itself or to components after it.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        // Kosaraju: order nodes by when a DFS finishes with them, then peel off
+        // components from the reversed graph, latest finisher first.
+        let mut seen = vec![false; self.len()];
+        let mut finished = Vec::with_capacity(self.len());
+        for root in 0..self.len() {
+            if seen[root] {
+                continue;
+            }
+            seen[root] = true;
+            let mut stack = vec![(root, 0)];
+            while let Some((v, i)) = stack.pop() {
+                match self.neighbours(v).get(i) {
+                    Some(&to) => {
+                        stack.push((v, i + 1));
+                        if !std::mem::replace(&mut seen[to], true) {
+                            stack.push((to, 0));
+                        }
+                    }
+                    None => finished.push(v),
+                }
+            }
+        }
+
+        let reversed = self.reversed();
+        let mut component = vec![None; self.len()];
+        let mut components = Vec::new();
+        for &root in finished.iter().rev() {
+            if component[root].is_some() {
+                continue;
+            }
+            let id = components.len();
+            component[root] = Some(id);
+            let mut members = vec![root];
+            let mut stack = vec![root];
+            while let Some(v) = stack.pop() {
+                for &to in reversed.neighbours(v) {
+                    if component[to].is_none() {
+                        component[to] = Some(id);
+                        members.push(to);
+                        stack.push(to);
+                    }
+                }
+            }
+            members.sort();
+            components.push(members);
+        }
+        components
+    }
+}
+
+/// Numbers for labels, handed out in the order they're first seen.
+#[derive(Debug, Clone, Default)]
+pub struct Labels<T> {
+    ids: HashMap<T, usize>,
+    names: Vec<T>,
+}
+
+impl<T: Eq + Hash + Clone> Labels<T> {
+    pub fn new() -> Labels<T> {
+        Labels { ids: HashMap::new(), names: Vec::new() }
+    }
+
+    /// The number for `name`, giving it the next one if it hasn't got one yet.
+    pub fn id(&mut self, name: T) -> usize {
+        if let Some(&id) = self.ids.get(&name) {
+            return id;
+        }
+        self.names.push(name.clone());
+        self.ids.insert(name, self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    pub fn get<Q>(&self, name: &Q) -> Option<usize>
+        where T: Borrow<Q>, Q: Eq + Hash + ?Sized
+    {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &T {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// Where repeatedly applying a function falls into a loop: after `tail` steps
+/// the values repeat every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub tail: u64,
+    pub length: u64,
+}
+
+/// Find the cycle that `start`, `next(start)`, `next(next(start))`, ... falls into, by
+/// Brent's algorithm; `next` must have finitely many values to visit or this won't return.
+pub fn find_cycle<T, F>(start: T, mut next: F) -> Cycle
+    where T: Clone + PartialEq, F: FnMut(&T) -> T
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = next(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        length += 1;
+    }
+
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+    let mut tail = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        tail += 1;
+    }
+    Cycle { tail, length }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 -> 1 -> 2 -> 0 and 2 -> 3 -> 4 -> 3, plus a lone node 5.
+    fn sample() -> Graph {
+        Graph::new(6, [(0, 1, 7), (1, 2, 1), (2, 0, 1), (2, 3, 2), (3, 4, 1), (4, 3, 1), (0, 2, 9)])
+    }
+
+    #[test]
+    fn test_adjacency() {
+        let g = sample();
+        assert_eq!(g.len(), 6);
+        assert_eq!(g.neighbours(0), &[1, 2]);
+        assert_eq!(g.edges(2).collect::<Vec<_>>(), vec![(0, 1), (3, 2)]);
+        assert!(g.neighbours(5).is_empty());
+        assert_eq!(g.reversed().neighbours(3), &[2, 4]);
+    }
+
+    #[test]
+    fn test_traversals() {
+        let g = sample();
+        assert_eq!(g.bfs(0), vec![Some(0), Some(1), Some(1), Some(2), Some(3), None]);
+        assert_eq!(g.dfs(0), vec![0, 1, 2, 3, 4]);
+        assert_eq!(g.dfs(5), vec![5]);
+        assert_eq!(g.dijkstra(0), vec![Some(0), Some(7), Some(8), Some(10), Some(11), None]);
+        // Going on from 1 overflows, which mustn't hide the lighter ways round.
+        let heavy = Graph::new(3, [(0, 1, u64::MAX), (1, 2, 1), (0, 2, 5), (2, 1, u64::MAX - 5)]);
+        assert_eq!(heavy.dijkstra(0), vec![Some(0), Some(u64::MAX), Some(5)]);
+        assert_eq!(Graph::new(3, [(0, 1, u64::MAX), (1, 2, 1)]).dijkstra(0), vec![Some(0), Some(u64::MAX), None]);
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let mut components = sample().strongly_connected_components();
+        let first = components.iter().position(|c| c == &vec![0, 1, 2]).unwrap();
+        let second = components.iter().position(|c| c == &vec![3, 4]).unwrap();
+        assert!(first < second);
+        components.sort();
+        assert_eq!(components, vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn test_labels() {
+        let mut labels = Labels::new();
+        assert_eq!(labels.id("AAA".to_string()), 0);
+        assert_eq!(labels.id("BBB".to_string()), 1);
+        assert_eq!(labels.id("AAA".to_string()), 0);
+        assert_eq!(labels.get("BBB"), Some(1));
+        assert_eq!(labels.get("CCC"), None);
+        assert_eq!(labels.name(1), "BBB");
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+        assert_eq!(find_cycle(0, |&x| if x == 5 { 3 } else { x + 1 }), Cycle { tail: 3, length: 3 });
+        assert_eq!(find_cycle(7, |&x| x), Cycle { tail: 0, length: 1 });
+        assert_eq!(find_cycle(1u64, |&x| x * 3 % 7), Cycle { tail: 0, length: 6 });
+    }
+}
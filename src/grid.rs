@@ -0,0 +1,230 @@
+//! A dense, rectangular grid of cells addressed by `Point`s, `x` across and `y` down.
+//!
+//! Points are signed so that stepping off an edge is just an out-of-bounds lookup.
+use std::fmt;
+
+use crate::error::{AocError, Result};
+use crate::geom::{Point, COMPASS4, COMPASS8};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+        where T: Clone
+    {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Parse one cell per character, one row per line; every line must be as wide as the first.
+    pub fn parse<F>(text: &str, mut cell: F) -> Result<Grid<T>>
+        where F: FnMut(char) -> Option<T>
+    {
+        let mut grid = Grid { width: 0, height: 0, cells: vec![] };
+        for (i, line) in text.lines().enumerate() {
+            let before = grid.cells.len();
+            for (offset, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| AocError::parse(i + 1, line, offset, format!("unexpected '{c}'")))?;
+                grid.cells.push(value);
+            }
+            let width = grid.cells.len() - before;
+            if i == 0 {
+                grid.width = width;
+            } else if width != grid.width {
+                let offset = line.char_indices().nth(grid.width).map_or(line.len(), |(o, _)| o);
+                return Err(AocError::parse(i + 1, line, offset,
+                                           format!("expected {} columns like the first row, found {width}", grid.width)));
+            }
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, p: Point) -> Option<usize> {
+        let (x, y) = (usize::try_from(p.x).ok()?, usize::try_from(p.y).ok()?);
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    fn pos(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.index(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Set the cell at `pos`, returning the old value, or `None` if `pos` is off the grid.
+    pub fn set(&mut self, pos: Point, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().enumerate().map(|(i, cell)| (self.pos(i), cell))
+    }
+
+    /// The up to four cells sharing an edge with `pos`, clockwise from above.
+    pub fn neighbours4(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.around(pos, &COMPASS4)
+    }
+
+    /// The up to eight cells sharing an edge or corner with `pos`, clockwise from above.
+    pub fn neighbours8(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.around(pos, &COMPASS8)
+    }
+
+    fn around<'a>(&'a self, pos: Point, directions: &'static [Point]) -> impl Iterator<Item = (Point, &'a T)> {
+        directions.iter().filter_map(move |&d| self.get(pos + d).map(|cell| (pos + d, cell)))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+        where T: PartialEq
+    {
+        self.cells.iter().position(|cell| cell == value).map(|i| self.pos(i))
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+        where T: PartialEq
+    {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` panics on 0, and a grid with no columns has no cells anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |column| self.cells.iter().skip(column).step_by(self.width))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+        where F: FnMut(&T) -> U
+    {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+        where T: Clone
+    {
+        Grid { width: self.height, height: self.width, cells: self.columns().flatten().cloned().collect() }
+    }
+
+    /// Turn a quarter clockwise.
+    pub fn rotate_right(&self) -> Grid<T>
+        where T: Clone
+    {
+        let mut cells: Vec<T> = Vec::with_capacity(self.cells.len());
+        for column in 0..self.width {
+            cells.extend(self.rows().rev().map(|row| row[column].clone()));
+        }
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    /// Turn a quarter anticlockwise.
+    pub fn rotate_left(&self) -> Grid<T>
+        where T: Clone
+    {
+        let mut cells: Vec<T> = Vec::with_capacity(self.cells.len());
+        for column in (0..self.width).rev() {
+            cells.extend(self.rows().map(|row| row[column].clone()));
+        }
+        Grid { width: self.height, height: self.width, cells }
+    }
+}
+
+/// One line per row, with no newline after the last.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            row.iter().try_for_each(|cell| write!(f, "{cell}"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse(text, Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let g = grid("abc\ndef\n");
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(g.get(Point::new(0, 2)), None);
+        assert_eq!(g.get(Point::new(-1, 0)), None);
+        assert_eq!(g.to_string(), "abc\ndef");
+
+        let e = Grid::parse("abc\nde\n", Some).unwrap_err();
+        assert!(e.to_string().starts_with("parse error at line 2, column 3: expected 3 columns"), "{e}");
+        let e = Grid::parse("..\n.x\n", |c| (c == '.').then_some(c)).unwrap_err();
+        assert!(e.to_string().starts_with("parse error at line 2, column 2: unexpected 'x'"), "{e}");
+    }
+
+    #[test]
+    fn test_set_and_find() {
+        let mut g = Grid::new(3, 2, '.');
+        assert_eq!(g.set(Point::new(1, 1), '#'), Some('.'));
+        assert_eq!(g.set(Point::new(1, 2), '#'), None);
+        g.set(Point::new(2, 0), '#');
+        assert_eq!(g.find(&'#'), Some(Point::new(2, 0)));
+        assert_eq!(g.find_all(&'#').collect::<Vec<_>>(), vec![Point::new(2, 0), Point::new(1, 1)]);
+        assert_eq!(g.find(&'x'), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = grid("abc\ndef\nghi");
+        let around = |ns: Vec<(Point, &char)>| ns.into_iter().map(|(_, &c)| c).collect::<String>();
+        assert_eq!(around(g.neighbours4(Point::new(1, 1)).collect()), "bfhd");
+        assert_eq!(around(g.neighbours8(Point::new(1, 1)).collect()), "bcfihgda");
+        assert_eq!(around(g.neighbours8(Point::new(0, 0)).collect()), "bed");
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.rows().collect::<Vec<_>>(), vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]]);
+        let columns: Vec<String> = g.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(g.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(g.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(g.rotate_right().rotate_left(), g);
+        assert_eq!(g.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
@@ -0,0 +1,127 @@
+//! Where puzzle input comes from: a file, stdin, or text built into the binary.
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{AocError, Result};
+
+// `SAMPLES`, from `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/samples.rs"));
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    File(PathBuf),
+    Stdin,
+    Embedded { name: &'static str, text: &'static str },
+}
+
+impl Input {
+    /// The puzzle input checked into the repo for `day`.
+    pub fn day(day: u32) -> Input {
+        Input::File(format!("day{day}.txt").into())
+    }
+
+    /// Sample `k` for `day`, i.e. `dayN-sampleK.txt`.
+    ///
+    /// The first sample of a day is often just `dayN-sample.txt`, so that's
+    /// used for `k = 1` when there's no `dayN-sample1.txt`. If neither file is
+    /// there, say when running away from the repo, the copy built into the
+    /// binary is used instead.
+    pub fn sample(day: u32, k: u32) -> Input {
+        sample(day, k, |path| path.exists())
+    }
+
+    /// The sample called `name`, like `day8-sample2.txt`, as built into the binary.
+    pub fn embedded(name: &str) -> Option<Input> {
+        SAMPLES.iter()
+            .find(|&&(n, _)| n == name)
+            .map(|&(name, text)| Input::Embedded { name, text })
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Input::File(path) => fs::read_to_string(path)
+                .map_err(|source| AocError::Io { path: path.clone(), source }),
+            Input::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)
+                    .map_err(|source| AocError::Io { path: self.to_string().into(), source })?;
+                Ok(text)
+            }
+            Input::Embedded { text, .. } => Ok(text.to_string()),
+        }
+    }
+}
+
+fn sample<F>(day: u32, k: u32, exists: F) -> Input
+    where F: Fn(&Path) -> bool
+{
+    let mut names = vec![format!("day{day}-sample{k}.txt")];
+    if k == 1 {
+        names.push(format!("day{day}-sample.txt"));
+    }
+    names.iter()
+        .find(|name| exists(Path::new(name)))
+        .map(|name| Input::File(name.into()))
+        .or_else(|| names.iter().find_map(|name| Input::embedded(name)))
+        .unwrap_or_else(|| Input::File(names.swap_remove(0).into()))
+}
+
+/// `-` is stdin; anything else is a path.
+impl FromStr for Input {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "" => Err("empty input path".into()),
+            "-" => Ok(Input::Stdin),
+            path => Ok(Input::File(path.into())),
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::File(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::Embedded { name, .. } => write!(f, "{name}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("-".parse::<Input>(), Ok(Input::Stdin));
+        assert_eq!("day8-sample3.txt".parse::<Input>(), Ok(Input::File("day8-sample3.txt".into())));
+        assert!("".parse::<Input>().is_err());
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(Input::sample(10, 3), Input::File("day10-sample3.txt".into()));
+        assert_eq!(Input::sample(8, 3), Input::File("day8-sample3.txt".into()));
+        assert_eq!(Input::sample(1, 1), Input::File("day1-sample.txt".into()));
+        assert_eq!(Input::day(1).to_string(), "day1.txt");
+    }
+
+    #[test]
+    fn test_embedded() {
+        let input = Input::embedded("day8-sample2.txt").unwrap();
+        assert_eq!(input.read().unwrap(), fs::read_to_string("day8-sample2.txt").unwrap());
+        assert_eq!(input.to_string(), "day8-sample2.txt");
+        assert_eq!(Input::embedded("day8.txt"), None);
+        // Away from the repo, where none of the files are.
+        let missing = |_: &Path| false;
+        assert_eq!(sample(8, 2, missing), input);
+        assert_eq!(sample(1, 1, missing), Input::embedded("day1-sample.txt").unwrap());
+        assert_eq!(sample(10, 0, missing), Input::embedded("day10-sample0.txt").unwrap());
+        assert_eq!(sample(8, 9, missing), Input::File("day8-sample9.txt".into()));
+    }
+}
@@ -0,0 +1,219 @@
+//! Sets of values stored as sorted, disjoint half-open ranges.
+use std::ops::Range;
+
+/// A set of values kept as ranges that are sorted, non-empty, and neither overlap
+/// nor touch, so two sets with the same values always have the same ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    /// Sort `ranges`, drop empty ones and merge any that overlap or touch.
+    fn normalize(mut ranges: Vec<Range<T>>) -> IntervalSet<T> {
+        ranges.retain(|r| r.start < r.end);
+        ranges.sort_by_key(|r| r.start);
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for r in ranges {
+            match merged.last_mut() {
+                Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+                _ => merged.push(r),
+            }
+        }
+        IntervalSet { ranges: merged }
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = IntervalSet::normalize(ranges);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // The last range starting at or before `value` is the only one that can hold it.
+        let i = self.ranges.partition_point(|r| r.start <= value);
+        i > 0 && value < self.ranges[i - 1].end
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    /// The ranges, in order.
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        IntervalSet::normalize(self.iter().chain(other.iter()).collect())
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let (mut a, mut b) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+        let mut ranges = Vec::new();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let (start, end) = (x.start.max(y.start), x.end.min(y.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            // Whichever ends first can't meet anything further along the other.
+            if x.end <= y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The values in `self` that aren't in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let mut cuts = other.ranges.iter().peekable();
+        for r in &self.ranges {
+            let mut start = r.start;
+            while let Some(cut) = cuts.peek() {
+                if cut.end <= start {
+                    cuts.next();
+                    continue;
+                }
+                if cut.start >= r.end {
+                    break;
+                }
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = cut.end;
+                if cut.end > r.end {
+                    break;
+                }
+                cuts.next();
+            }
+            if start < r.end {
+                ranges.push(start..r.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The ranges cut at each of `boundaries` that falls strictly inside one, so no
+    /// piece straddles a boundary. The pieces touch, so they're returned as a list.
+    pub fn split(&self, boundaries: impl IntoIterator<Item = T>) -> Vec<Range<T>> {
+        let mut boundaries: Vec<T> = boundaries.into_iter().collect();
+        boundaries.sort();
+        boundaries.dedup();
+        let mut pieces = Vec::new();
+        for r in &self.ranges {
+            let mut start = r.start;
+            let first = boundaries.partition_point(|&b| b <= r.start);
+            for &b in boundaries[first..].iter().take_while(|&&b| b < r.end) {
+                pieces.push(start..b);
+                start = b;
+            }
+            pieces.push(start..r.end);
+        }
+        pieces
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        IntervalSet::normalize(vec![range])
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        IntervalSet::normalize(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn set(ranges: &[Range<u64>]) -> IntervalSet<u64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(set(&[5..7, 1..3, 2..4, 7..9, 10..10]).iter().collect::<Vec<_>>(), vec![1..4, 5..9]);
+        #[allow(clippy::reversed_empty_ranges)]
+        let backwards = 5..2;
+        assert!(set(&[3..3, backwards]).is_empty());
+        let mut s = IntervalSet::from(1..3);
+        s.insert(3..5);
+        assert_eq!(s, IntervalSet::from(1..5));
+        assert_eq!(s.min(), Some(1));
+        assert_eq!(IntervalSet::<u64>::new().min(), None);
+    }
+
+    #[test]
+    fn test_contains() {
+        let s = set(&[1..3, 5..6]);
+        assert_eq!((0..8).filter(|&v| s.contains(v)).collect::<Vec<_>>(), vec![1, 2, 5]);
+    }
+
+    #[test]
+    fn test_union_intersection_difference() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 30..35]);
+        assert_eq!(a.union(&b), IntervalSet::from(0..35));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..30]));
+        assert_eq!(b.difference(&a), set(&[10..20, 30..35]));
+        assert_eq!(a.difference(&set(&[2..3, 4..5, 8..22])), set(&[0..2, 3..4, 5..8, 22..30]));
+        assert_eq!(a.intersection(&IntervalSet::new()), IntervalSet::new());
+        assert_eq!(a.difference(&IntervalSet::from(0..100)), IntervalSet::new());
+    }
+
+    #[test]
+    fn test_split() {
+        let s = set(&[0..10, 20..30]);
+        assert_eq!(s.split([5, 0, 20, 25, 25, 40]), vec![0..5, 5..10, 20..25, 25..30]);
+        assert_eq!(s.split([]), vec![0..10, 20..30]);
+    }
+
+    fn ranges() -> impl Strategy<Value = Vec<Range<u64>>> {
+        prop::collection::vec((0..60u64, 0..10u64).prop_map(|(start, len)| start..start + len), 0..6)
+    }
+
+    fn values(ranges: &[Range<u64>]) -> Vec<u64> {
+        (0..80).filter(|v| ranges.iter().any(|r| r.contains(v))).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn matches_plain_sets(a in ranges(), b in ranges()) {
+            let (sa, sb) = (set(&a), set(&b));
+            let (va, vb) = (values(&a), values(&b));
+            let members = |s: &IntervalSet<u64>| (0..80).filter(|&v| s.contains(v)).collect::<Vec<_>>();
+            prop_assert_eq!(members(&sa), va.clone());
+            prop_assert_eq!(members(&sa.union(&sb)), (0..80).filter(|v| va.contains(v) || vb.contains(v)).collect::<Vec<_>>());
+            prop_assert_eq!(members(&sa.intersection(&sb)), (0..80).filter(|v| va.contains(v) && vb.contains(v)).collect::<Vec<_>>());
+            prop_assert_eq!(members(&sa.difference(&sb)), (0..80).filter(|v| va.contains(v) && !vb.contains(v)).collect::<Vec<_>>());
+            // Whatever the operation, the result is normalized.
+            for s in [sa.union(&sb), sa.intersection(&sb), sa.difference(&sb)] {
+                prop_assert_eq!(s.iter().collect::<IntervalSet<u64>>(), s.clone());
+                prop_assert!(s.ranges.windows(2).all(|w| w[0].end < w[1].start));
+            }
+        }
+    }
+}
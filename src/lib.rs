@@ -0,0 +1,17 @@
+pub mod automaton;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod parse;
+pub mod run;
+pub mod scaffold;
+pub mod solution;
+pub mod verify;
+
+pub use error::{AocError, Result};
@@ -1,3 +1,366 @@
+use std::fmt;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+
+use clap::{Parser, Subcommand};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use tracing_subscriber::EnvFilter;
+use aoc::bench::{self, Micros, Phase, Timing};
+use aoc::days::{self, Day, DAYS};
+use aoc::days::day1::{Day1, Dictionary, Digits, OverlapPolicy};
+use aoc::input::Input;
+use aoc::solution::{Part, Solution};
+use aoc::run::{self, Task};
+use aoc::scaffold;
+use aoc::verify::{self, Report};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    /// Log what the solvers are doing to stderr: `-v` for debug, `-vv` for trace.
+    /// `RUST_LOG` overrides this, e.g. `RUST_LOG=aoc::days::day5=trace`.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day's solution, or `all` of them.
+    Run {
+        /// Day number or `all`.
+        day: Which,
+        /// Only run this part (1 or 2).
+        #[arg(long)]
+        part: Option<Part>,
+        /// Puzzle input file, or `-` for stdin; defaults to `dayN.txt`.
+        #[arg(long)]
+        input: Option<Input>,
+        /// Use sample K, `dayN-sampleK.txt`, as the input; the binary has a copy of each.
+        #[arg(long, value_name = "K", conflicts_with = "input")]
+        sample: Option<u32>,
+        /// With `all`, how many days to run at once; defaults to one per CPU.
+        #[arg(long, short = 'j', value_name = "N")]
+        jobs: Option<usize>,
+        /// Day 1 only: words for numbers in part 2, one `token = value` per line,
+        /// instead of English `one` to `nine`.
+        #[arg(long, value_name = "PATH")]
+        dictionary: Option<PathBuf>,
+    },
+    /// Check every solution against the known answers.
+    Verify {
+        /// Answers file.
+        #[arg(long, default_value = verify::ANSWERS)]
+        answers: PathBuf,
+        /// Directory of expected sample output.
+        #[arg(long, default_value = verify::EXPECTED)]
+        expected: PathBuf,
+        /// Skip answers marked slow.
+        #[arg(long)]
+        quick: bool,
+    },
+    /// Time parsing and each part over several iterations.
+    Bench {
+        /// Day number or `all`.
+        day: Which,
+        /// How many times to run each phase.
+        #[arg(long, short = 'n', default_value_t = 10)]
+        iterations: usize,
+        /// Puzzle input file, or `-` for stdin; defaults to `dayN.txt`.
+        #[arg(long)]
+        input: Option<Input>,
+        /// Write the timings to this file as JSON.
+        #[arg(long, value_name = "PATH")]
+        json: Option<PathBuf>,
+        /// Compare with timings saved by `--json`, failing if any phase got slower.
+        #[arg(long, value_name = "PATH")]
+        baseline: Option<PathBuf>,
+        /// How much slower than the baseline's median counts as a regression, in percent.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Print a random puzzle input for a day.
+    Generate {
+        /// Day number.
+        #[arg(value_parser = day_number)]
+        day: u32,
+        /// Seed for the random generator; the same seed gives the same input.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How big an input to make; each day has its own default, about the size of a real input.
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Show how day 1 reads each line: every digit and word found, the first and last
+    /// picked, and what the line adds to the total.
+    Explain {
+        /// Part 1 only counts digits; part 2 counts words too.
+        #[arg(long, default_value_t = Part::Two)]
+        part: Part,
+        /// Puzzle input file, or `-` for stdin; defaults to `day1.txt`.
+        #[arg(long)]
+        input: Option<Input>,
+        /// Use sample K, `day1-sampleK.txt`, as the input; the binary has a copy of each.
+        #[arg(long, value_name = "K", conflicts_with = "input")]
+        sample: Option<u32>,
+        /// Words for numbers, one `token = value` per line, instead of English `one` to `nine`.
+        #[arg(long, value_name = "PATH")]
+        dictionary: Option<PathBuf>,
+        /// How to read words that share letters: overlapping, leftmost-longest or greedy.
+        #[arg(long, default_value = "overlapping")]
+        policy: OverlapPolicy,
+    },
+    /// Start a new day from the template: its module, registered with the runner,
+    /// and empty sample and expected output files.
+    New {
+        /// Day number.
+        day: u32,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Which {
+    All,
+    Day(u32),
+}
+
+impl FromStr for Which {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Which::All);
+        }
+        let n: u32 = s.parse().map_err(|_| format!("expected a day number or `all`, got `{s}`"))?;
+        days::get(n).map(|_| Which::Day(n)).ok_or_else(|| format!("no solution for day {n}"))
+    }
+}
+
+fn day_number(s: &str) -> Result<u32, String> {
+    match s.parse()? {
+        Which::Day(n) => Ok(n),
+        Which::All => Err("expected a day number".into()),
+    }
+}
+
+/// `--input`, else `--sample`, else the day's puzzle input.
+fn choose_input(day: u32, input: Option<Input>, sample: Option<u32>) -> Input {
+    match (input, sample) {
+        (Some(input), _) => input,
+        (None, Some(k)) => Input::sample(day, k),
+        (None, None) => Input::day(day),
+    }
+}
+
+/// One part of `day`; with `digits`, day 1's part 2 reads those words instead of English.
+fn solve(day: &Day, input: &Input, text: &str, part: Part, digits: Option<&Digits>) -> aoc::Result<String> {
+    let answer = match (digits, part) {
+        (Some(digits), Part::Two) => Day1::part2_with(text, digits).map(|a| a.to_string()),
+        _ => (day.solve)(text, part),
+    };
+    answer.map_err(|e| e.in_file(input.to_string()))
+}
+
+/// Both parts of `day` over one parse, with `digits` as for `solve`.
+fn solve_both(day: &Day, text: &str, digits: Option<&Digits>) -> aoc::Result<[aoc::Result<String>; 2]> {
+    match digits {
+        Some(digits) => {
+            let text = Day1::parse(text)?;
+            Ok([Day1::part1(&text).map(|a| a.to_string()), Day1::part2_with(&text, digits).map(|a| a.to_string())])
+        }
+        None => (day.solve_both)(text),
+    }
+}
+
+fn fail(e: impl fmt::Display) -> ! {
+    eprintln!("error: {e}");
+    process::exit(1);
+}
+
+/// Log to stderr, so stdout only ever has answers on it.
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => "warn",
+        1 => "warn,aoc=debug",
+        _ => "warn,aoc=trace",
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .init();
+}
+
 fn main() {
-    println!("Hello, world!");
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    match cli.command {
+        Command::Run { day: Which::Day(n), part, input, sample, dictionary, .. } => {
+            let day = days::get(n).unwrap();
+            let digits = dictionary.map(|path| {
+                if n != 1 {
+                    eprintln!("error: --dictionary is only for day 1");
+                    process::exit(2);
+                }
+                Dictionary::load(path).unwrap_or_else(|e| fail(e)).build()
+            });
+            let input = choose_input(n, input, sample);
+            let text = input.read().unwrap_or_else(|e| fail(e));
+            match part {
+                Some(part) => println!("{}", solve(day, &input, &text, part, digits.as_ref()).unwrap_or_else(|e| fail(e))),
+                None => {
+                    // A failed part is reported, but the other still gets its answer.
+                    let answers = solve_both(day, &text, digits.as_ref())
+                        .unwrap_or_else(|e| fail(e.in_file(input.to_string())));
+                    let mut failed = false;
+                    for (part, answer) in Part::BOTH.into_iter().zip(answers) {
+                        match answer {
+                            Ok(answer) => println!("part {part}: {answer}"),
+                            Err(e) => {
+                                eprintln!("error: part {part}: {}", e.in_file(input.to_string()));
+                                failed = true;
+                            }
+                        }
+                    }
+                    if failed {
+                        process::exit(1);
+                    }
+                }
+            }
+        }
+        Command::Run { day: Which::All, part, input, sample, jobs, dictionary } => {
+            if input.is_some() || sample.is_some() || dictionary.is_some() {
+                eprintln!("error: --input, --sample and --dictionary need a single day");
+                process::exit(2);
+            }
+            let answers = verify::load(verify::ANSWERS).unwrap_or_else(|e| {
+                eprintln!("warning: {e}; answers won't be checked");
+                vec![]
+            });
+            let tasks: Vec<Task> = DAYS.iter()
+                .map(|day| Task { day, part, input: Input::day(day.number) })
+                .collect();
+            let runs = run::run_all(&tasks, jobs.unwrap_or(0)).unwrap_or_else(|e| fail(e));
+
+            let width = runs.iter()
+                .flat_map(|r| &r.answers)
+                .filter_map(|(_, answer)| answer.as_ref().ok())
+                .map(String::len)
+                .fold("answer".len(), usize::max);
+            println!("{:>3}  {:>4}  {:<width$}  {:>9}  status", "day", "part", "answer", "time");
+            let mut problems = vec![];
+            for (task, run) in tasks.iter().zip(&runs) {
+                // Both parts share one parse, so the time is for the day as a whole.
+                let mut time = Micros(run.elapsed.as_secs_f64() * 1e6).to_string();
+                for (part, result) in &run.answers {
+                    let known = answers.iter()
+                        .find(|a| a.day == run.day && a.part == *part && task.input == Input::File(a.input.clone()));
+                    let status = match (known, result) {
+                        (Some(answer), _) => {
+                            let report = Report { answer: answer.clone(), outcome: verify::outcome(answer, result.clone()) };
+                            if !report.is_ok() {
+                                problems.push(report.to_string());
+                            }
+                            report.status()
+                        }
+                        (None, Ok(_)) => "unverified",
+                        (None, Err(e)) => {
+                            problems.push(format!("day {} part {part}: error: {e}", run.day));
+                            "fail"
+                        }
+                    };
+                    let answer = result.as_deref().unwrap_or("-");
+                    println!("{:>3}  {part:>4}  {answer:<width$}  {time:>9}  {status}", run.day);
+                    time.clear();
+                }
+            }
+            for problem in &problems {
+                eprintln!("{problem}");
+            }
+            if !problems.is_empty() {
+                process::exit(1);
+            }
+        }
+        Command::Verify { answers, expected, quick } => {
+            let answers = verify::samples(&expected)
+                .and_then(|mut samples| {
+                    samples.extend(verify::load(&answers)?);
+                    Ok(samples)
+                })
+                .unwrap_or_else(|e| fail(e));
+            let reports = verify::verify(&answers, !quick);
+            for report in &reports {
+                println!("{report}");
+            }
+            let bad = reports.iter().filter(|r| !r.is_ok()).count();
+            println!("{} checked, {} ok, {} bad", reports.len(), reports.len() - bad, bad);
+            if bad > 0 {
+                process::exit(1);
+            }
+        }
+        Command::Bench { day, iterations, input, json, baseline, threshold } => {
+            let days = match day {
+                Which::Day(n) => vec![days::get(n).unwrap()],
+                Which::All if input.is_some() => {
+                    eprintln!("error: --input needs a single day");
+                    process::exit(2);
+                }
+                Which::All => DAYS.iter().collect(),
+            };
+            let mut report = bench::Report { iterations, timings: vec![] };
+            println!("{:>3}  {:<6}  {:>9}  {:>9}  {:>9}", "day", "phase", "mean", "median", "stddev");
+            for day in days {
+                let input = input.clone().unwrap_or_else(|| Input::day(day.number));
+                let text = input.read().unwrap_or_else(|e| fail(e));
+                let stats = (day.bench)(&text, iterations).unwrap_or_else(|e| fail(e.in_file(input.to_string())));
+                for (phase, stats) in Phase::ALL.into_iter().zip(stats) {
+                    println!("{:>3}  {phase:<6}  {:>9}  {:>9}  {:>9}", day.number,
+                             Micros(stats.mean_us), Micros(stats.median_us), Micros(stats.stddev_us));
+                    report.timings.push(Timing { day: day.number, phase, stats });
+                }
+            }
+            if let Some(path) = json {
+                report.save(path).unwrap_or_else(|e| fail(e));
+            }
+            if let Some(path) = baseline {
+                let baseline = bench::Report::load(path).unwrap_or_else(|e| fail(e));
+                let regressions = bench::compare(&baseline, &report, threshold / 100.0);
+                for regression in &regressions {
+                    println!("regression: {regression}");
+                }
+                if !regressions.is_empty() {
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Generate { day, seed, size } => {
+            let day = days::get(day).unwrap();
+            let mut rng = StdRng::seed_from_u64(seed);
+            print!("{}", (day.generate)(&mut rng, size.unwrap_or(day.size)));
+        }
+        Command::Explain { part, input, sample, dictionary, policy } => {
+            let input = choose_input(1, input, sample);
+            let text = input.read().unwrap_or_else(|e| fail(e));
+            let dictionary = match (part, dictionary) {
+                (Part::One, None) => Dictionary::new(),
+                (Part::One, Some(_)) => {
+                    eprintln!("error: part 1 only counts digits, so --dictionary needs --part 2");
+                    process::exit(2);
+                }
+                (Part::Two, None) => Dictionary::english(),
+                (Part::Two, Some(path)) => Dictionary::load(path).unwrap_or_else(|e| fail(e)),
+            };
+            println!("{}", dictionary.build().explain(&text, policy));
+        }
+        Command::New { day } => {
+            for path in scaffold::new_day(Path::new(""), day).unwrap_or_else(|e| fail(e)) {
+                println!("wrote {}", path.display());
+            }
+        }
+    }
 }
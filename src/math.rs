@@ -0,0 +1,230 @@
+//! Number theory and sequence helpers. Anything that can overflow returns `None` instead.
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// The least common multiple, or `None` if it doesn't fit in a `u64`; 0 if either is 0.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The gcd of all of `values`; 0 if there are none.
+pub fn gcd_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// The lcm of all of `values`; 1 if there are none.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// `(g, x, y)` with `g = gcd(a, b)`, never negative, and `a * x + b * y = g`.
+///
+/// The coefficients are no bigger than `a` and `b`, so this can't overflow
+/// while they fit in a `u64` either way round.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 { (-r0, -x0, -y0) } else { (r0, x0, y0) }
+}
+
+/// Solve `x ≡ residue (mod modulus)` for every `(residue, modulus)` at once by the
+/// Chinese remainder theorem, giving `(x, lcm of the moduli)` with the smallest `x`.
+///
+/// The moduli needn't be coprime. `None` if the congruences contradict each other,
+/// a modulus is 0, or the lcm doesn't fit in a `u64`.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    congruences.into_iter().try_fold((0, 1), |(r1, m1), (r2, m2)| {
+        if m2 == 0 {
+            return None;
+        }
+        let r2 = r2 % m2;
+        let (g, p, _) = extended_gcd(m1.into(), m2.into());
+        let (g, p) = (g as u64, p.rem_euclid(m2 as i128) as u128);
+        let diff = (r2 as i128 - r1 as i128).rem_euclid(m2 as i128) as u64;
+        if !diff.is_multiple_of(g) {
+            return None;
+        }
+        // x = r1 + m1 * k, where m1 * k ≡ diff (mod m2), so (m1 / g) * k ≡ diff / g (mod m2 / g).
+        let step = m2 / g;
+        let k = (diff / g) as u128 % step as u128 * (p % step as u128) % step as u128;
+        let modulus = (m1 / g).checked_mul(m2)?;
+        Some((r1 + m1 * k as u64, modulus))
+    })
+}
+
+/// The largest `r` with `r * r <= n`.
+pub fn isqrt(n: u64) -> u64 {
+    isqrt128(n.into()) as u64
+}
+
+/// The largest `r` with `r * r <= n`.
+pub fn isqrt128(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method from a power of two at or above the root only ever comes down.
+    let mut r = 1 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let next = (r + n / r) / 2;
+        if next >= r {
+            return r;
+        }
+        r = next;
+    }
+}
+
+/// `n` choose `k`, or `None` if it doesn't fit in a `u64`.
+pub fn binomial(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    // The smaller k keeps every partial product below the answer.
+    choose(n.into(), k.min(n - k)).and_then(|c| c.try_into().ok())
+}
+
+/// `a (a - 1) ... (a - k + 1) / k!`, which is a whole number even for negative `a`.
+fn choose(a: i128, k: u64) -> Option<i128> {
+    (0..k).try_fold(1i128, |c, t| {
+        // c is a choose t, so c * (a - t) is (a choose t + 1) * (t + 1).
+        Some(c.checked_mul(a.checked_sub(t.into())?)? / i128::from(t + 1))
+    })
+}
+
+/// The value at `x` of the lowest-degree polynomial through `(i, values[i])` for each
+/// `i`, by Lagrange interpolation; `None` if anything along the way overflows.
+pub fn lagrange(values: &[i64], x: i64) -> Option<i64> {
+    let n = values.len() as i128;
+    let x = i128::from(x);
+    if (0..n).contains(&x) {
+        return Some(values[x as usize]);
+    }
+    let mut sum: i128 = 0;
+    for (i, &y) in (0..).zip(values) {
+        // The basis polynomial for i, split at i into two generalized binomials:
+        // prod over j < i of (x - j) / (i - j), and over j > i of (x - j) / (i - j).
+        let below = choose(x, i as u64)?;
+        let above = choose(x.checked_sub(i + 1)?, (n - 1 - i) as u64)?;
+        let sign = if (n - 1 - i) % 2 == 0 { 1 } else { -1 };
+        let term = i128::from(y).checked_mul(below)?.checked_mul(above)?.checked_mul(sign)?;
+        sum = sum.checked_add(term)?;
+    }
+    sum.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(gcd_all([]), 0);
+        assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm_all([1 << 40, 3 << 30, 5 << 20]), Some(15 << 40));
+        assert_eq!(lcm_all([1 << 40, 3, 5 << 30, 7 << 20]), Some(105 << 40));
+        assert_eq!(lcm_all([1 << 63, 3]), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(-4, 6).0, 2);
+        assert_eq!(extended_gcd(0, -5), (5, 0, -1));
+        let (a, b) = (u64::MAX as i128, (u64::MAX - 2) as i128);
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!(a * x + b * y, g);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Not coprime, but consistent.
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(1, 0)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(5, 3)]), Some((2, 3)));
+        // The answer would need a modulus past u64::MAX.
+        assert_eq!(crt([(1, u64::MAX), (0, 2)]), None);
+        let (x, m) = crt([(3, 1 << 32), (5, (1 << 32) - 1)]).unwrap();
+        assert_eq!((x % (1 << 32), x % ((1 << 32) - 1), m), (3, 5, u64::MAX - (1 << 32) + 1));
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!((0..10).map(isqrt).collect::<Vec<_>>(), vec![0, 1, 1, 1, 2, 2, 2, 2, 2, 3]);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt128(u128::MAX), u64::MAX as u128);
+        assert_eq!(isqrt128((1 << 100) - 1), (1 << 50) - 1);
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!((0..=5).map(|k| binomial(5, k).unwrap()).collect::<Vec<_>>(), vec![1, 5, 10, 10, 5, 1]);
+        assert_eq!(binomial(3, 4), Some(0));
+        assert_eq!(binomial(67, 33), Some(14226520737620288370));
+        assert_eq!(binomial(68, 34), None);
+        assert_eq!(binomial(u64::MAX, u64::MAX - 1), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_lagrange() {
+        // 2x^2 - 3x + 1
+        let values = [1, 0, 3, 10];
+        assert_eq!(lagrange(&values, 4), Some(21));
+        assert_eq!(lagrange(&values, -1), Some(6));
+        assert_eq!(lagrange(&values, 2), Some(3));
+        assert_eq!(lagrange(&[0, 3, 6, 9, 12, 15], 6), Some(18));
+        assert_eq!(lagrange(&[7], 100), Some(7));
+        assert_eq!(lagrange(&[], 3), Some(0));
+        assert_eq!(lagrange(&[0, i64::MAX], 2), None);
+    }
+
+    proptest! {
+        #[test]
+        fn isqrt_is_floor_root(n: u128) {
+            let r = isqrt128(n);
+            prop_assert!(r * r <= n);
+            prop_assert!((r + 1).checked_mul(r + 1).is_none_or(|s| s > n));
+        }
+
+        #[test]
+        fn crt_matches_search(congruences in prop::collection::vec((0..100u64, 1..12u64), 0..4)) {
+            // Every modulus is below 12, so any solution turns up below their lcm, at most 27720.
+            let brute = (0..27720u64).find(|&x| congruences.iter().all(|&(r, m)| x % m == r % m));
+            match crt(congruences.iter().copied()) {
+                Some((x, m)) => {
+                    prop_assert_eq!(Some(x), brute);
+                    prop_assert_eq!(Some(m), lcm_all(congruences.iter().map(|&(_, m)| m)));
+                }
+                None => prop_assert_eq!(brute, None),
+            }
+        }
+
+        #[test]
+        fn lagrange_matches_polynomials(coefficients in prop::collection::vec(-9..=9i64, 1..8), x in -30..30i64) {
+            let eval = |x: i64| coefficients.iter().rev().fold(0, |accum, c| accum * x + c);
+            let values: Vec<i64> = (0..coefficients.len() as i64).map(eval).collect();
+            prop_assert_eq!(lagrange(&values, x), Some(eval(x)));
+        }
+    }
+}
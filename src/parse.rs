@@ -0,0 +1,187 @@
+//! winnow parsers shared by the days.
+use winnow::prelude::*;
+use winnow::ascii::{dec_int, dec_uint, Int, Uint};
+use winnow::token::take_while;
+use winnow::combinator::{repeat, separated};
+use winnow::error::{ContextError, StrContext, StrContextValue};
+use crate::error::{AocError, Result};
+
+/// Parse an unsigned decimal number of any width, e.g. `number::<u64>`.
+pub fn number<T: Uint>(input: &mut &str) -> PResult<T> {
+    dec_uint.parse_next(input)
+}
+
+/// Parse a decimal number with an optional leading `-` or `+`.
+pub fn signed_number<T: Int>(input: &mut &str) -> PResult<T> {
+    dec_int.parse_next(input)
+}
+
+/// One or more spaces.
+pub fn multiple_space(input: &mut &str) -> PResult<()> {
+    repeat(1.., ' ')
+        .context(StrContext::Expected(StrContextValue::CharLiteral(' ')))
+        .parse_next(input)
+}
+
+/// Items separated by one or more spaces.
+pub fn list<'i, O, P>(item: P) -> impl Parser<&'i str, Vec<O>, ContextError>
+where P: Parser<&'i str, O, ContextError>,
+{
+    separated(0.., item, multiple_space)
+}
+
+pub fn number_list<T: Uint>(input: &mut &str) -> PResult<Vec<T>> {
+    list(number).parse_next(input)
+}
+
+pub fn signed_number_list<T: Int>(input: &mut &str) -> PResult<Vec<T>> {
+    list(signed_number).parse_next(input)
+}
+
+/// The literal `tag`; failures report "expected 'tag'".
+pub fn literal<'i>(tag: &'static str) -> impl Parser<&'i str, &'i str, ContextError> {
+    tag.context(StrContext::Expected(StrContextValue::StringLiteral(tag)))
+}
+
+/// Context for a failure that expected `what`, e.g. `expected("card id")`.
+pub fn expected(what: &'static str) -> StrContext {
+    StrContext::Expected(StrContextValue::Description(what))
+}
+
+/// A run of uppercase letters and digits like `AAA` or `11Z`.
+pub fn label(input: &mut &str) -> PResult<String> {
+    take_while(1.., ('0'..='9', 'A'..='Z'))
+        .map(String::from)
+        .parse_next(input)
+}
+
+/// Parse all of line `n` with `parser`.
+pub fn parse_line<O, P>(n: usize, line: &str, mut parser: P) -> Result<O>
+where P: for<'i> Parser<&'i str, O, ContextError>,
+{
+    parser.parse(line).map_err(|e| AocError::parse(n, line, e.offset(), describe(e.inner())))
+}
+
+/// Turn winnow's context into a message like "expected ' |' after winning numbers".
+///
+/// Labels are written as the phrase that follows, e.g. "after winning numbers".
+pub fn describe(e: &ContextError) -> String {
+    let mut expected = Vec::new();
+    let mut label = None;
+    for c in e.context() {
+        match c {
+            StrContext::Expected(StrContextValue::CharLiteral(c)) => expected.push(format!("'{c}'")),
+            StrContext::Expected(StrContextValue::StringLiteral(s)) => expected.push(format!("'{s}'")),
+            StrContext::Expected(v) => expected.push(v.to_string()),
+            StrContext::Label(l) => { label.get_or_insert(*l); }
+            _ => {}
+        }
+    }
+    let mut message = match expected.as_slice() {
+        [] => "unexpected input".to_string(),
+        [one] => format!("expected {one}"),
+        [init @ .., last] => format!("expected {} or {last}", init.join(", ")),
+    };
+    if let Some(label) = label {
+        message.push(' ');
+        message.push_str(label);
+    }
+    message
+}
+
+/// Parse every line of `input` with `parser`.
+pub fn lines<O, P>(input: &str, mut parser: P) -> Result<Vec<O>>
+where P: for<'i> Parser<&'i str, O, ContextError>,
+{
+    input.lines()
+        .enumerate()
+        .map(|(i, l)| parse_line(i + 1, l, parser.by_ref()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number() {
+        let mut input = "12";
+
+        let n: u32 = number.parse_next(&mut input).unwrap();
+        assert_eq!(n, 12);
+    }
+
+    #[test]
+    fn test_number_overflow() {
+        let mut input = "300";
+
+        assert!(number::<u8>.parse_next(&mut input).is_err());
+        let n: u64 = number.parse_next(&mut "644102312401023").unwrap();
+        assert_eq!(n, 644102312401023);
+    }
+
+    #[test]
+    fn test_number_empty() {
+        let mut input = "|";
+
+        assert!(number::<u32>.parse_next(&mut input).is_err());
+    }
+
+    #[test]
+    fn test_signed_number() {
+        let n: i64 = signed_number.parse_next(&mut "-12").unwrap();
+        assert_eq!(n, -12);
+        let n: i64 = signed_number.parse_next(&mut "7").unwrap();
+        assert_eq!(n, 7);
+    }
+
+    #[test]
+    fn test_number_list() {
+        let mut input = "83 86  6 31 | 1";
+
+        let l: Vec<u32> = number_list.parse_next(&mut input).unwrap();
+        assert_eq!(l, vec![83, 86, 6, 31]);
+        assert_eq!(input, " | 1");
+    }
+
+    #[test]
+    fn test_signed_number_list() {
+        let l: Vec<i64> = signed_number_list.parse_next(&mut "10 -3 0").unwrap();
+        assert_eq!(l, vec![10, -3, 0]);
+    }
+
+    #[test]
+    fn test_lines() {
+        let l: Vec<Vec<u32>> = lines("1 2\n3", number_list).unwrap();
+        assert_eq!(l, vec![vec![1, 2], vec![3]]);
+
+        match lines("1 2\n3 x", number_list::<u32>) {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 2)),
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+
+    #[test]
+    fn test_describe() {
+        fn card(input: &mut &str) -> PResult<u32> {
+            let n = number(input)?;
+            let _ = literal(" |").context(StrContext::Label("after winning numbers")).parse_next(input)?;
+            Ok(n)
+        }
+        match parse_line(3, "12 x", card) {
+            Err(AocError::Parse { line, column, message, .. }) => {
+                assert_eq!((line, column), (3, 3));
+                assert_eq!(message, "expected ' |' after winning numbers");
+            }
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+
+    #[test]
+    fn test_label() {
+        let mut input = "11A = (11B, XXX)";
+
+        assert_eq!(label.parse_next(&mut input).unwrap(), "11A");
+        assert_eq!(input, " = (11B, XXX)");
+    }
+}
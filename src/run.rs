@@ -0,0 +1,110 @@
+//! Solve days and parts, many at once on a thread pool.
+use std::panic;
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+use tracing::debug;
+use crate::days::Day;
+use crate::error::AocError;
+use crate::input::Input;
+use crate::solution::Part;
+
+pub struct Task {
+    pub day: &'static Day,
+    /// `None` for both parts, which parses the input only once.
+    pub part: Option<Part>,
+    pub input: Input,
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub day: u32,
+    /// Each part asked for, with its answer or what went wrong.
+    pub answers: Vec<(Part, Result<String, String>)>,
+    pub elapsed: Duration,
+}
+
+/// Solve one part, catching panics so one bad day can't take down the rest.
+pub fn solve(day: &Day, part: Part, input: &Input) -> Result<String, String> {
+    let text = input.read().map_err(|e| e.to_string())?;
+    match panic::catch_unwind(|| (day.solve)(&text, part)) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(e)) => Err(e.in_file(input.to_string()).to_string()),
+        Err(e) => Err(panic_message(&e)),
+    }
+}
+
+/// Solve both parts over one parse of the input. If reading or parsing it fails,
+/// or the solver panics, both parts get the same error.
+pub fn solve_both(day: &Day, input: &Input) -> [Result<String, String>; 2] {
+    let text = match input.read() {
+        Ok(text) => text,
+        Err(e) => return [Err(e.to_string()), Err(e.to_string())],
+    };
+    let in_file = |e: AocError| e.in_file(input.to_string()).to_string();
+    match panic::catch_unwind(|| (day.solve_both)(&text)) {
+        Ok(Ok(answers)) => answers.map(|a| a.map_err(in_file)),
+        Ok(Err(e)) => {
+            let e = in_file(e);
+            [Err(e.clone()), Err(e)]
+        }
+        Err(e) => [Err(panic_message(&e)), Err(panic_message(&e))],
+    }
+}
+
+/// Run `tasks` on `jobs` threads (0 for one per CPU), returning results in task order.
+pub fn run_all(tasks: &[Task], jobs: usize) -> Result<Vec<Run>, String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|e| e.to_string())?;
+    Ok(pool.install(|| {
+        tasks.par_iter()
+            .map(|task| {
+                let start = Instant::now();
+                let answers = match task.part {
+                    Some(part) => vec![(part, solve(task.day, part, &task.input))],
+                    None => Part::BOTH.into_iter().zip(solve_both(task.day, &task.input)).collect(),
+                };
+                let elapsed = start.elapsed();
+                let ok = answers.iter().all(|(_, answer)| answer.is_ok());
+                debug!(day = task.day.number, part = ?task.part, ?elapsed, ok, "solved");
+                Run { day: task.day.number, answers, elapsed }
+            })
+            .collect()
+    }))
+}
+
+fn panic_message(e: &Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = e.downcast_ref::<&str>() {
+        format!("panicked: {s}")
+    } else if let Some(s) = e.downcast_ref::<String>() {
+        format!("panicked: {s}")
+    } else {
+        "panicked".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_run_all() {
+        let day = days::get(1).unwrap();
+        let sample = Input::Embedded { name: "sample", text: "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n" };
+        let tasks = vec![
+            Task { day, part: Some(Part::One), input: sample.clone() },
+            Task { day, part: None, input: Input::Embedded { name: "bad", text: "abc\n" } },
+            Task { day, part: None, input: sample },
+        ];
+        let runs = run_all(&tasks, 2).unwrap();
+        let parts = |run: &Run| run.answers.iter().map(|&(part, _)| part).collect::<Vec<_>>();
+        assert_eq!(runs.iter().map(parts).collect::<Vec<_>>(), vec![vec![Part::One], Part::BOTH.to_vec(), Part::BOTH.to_vec()]);
+        assert_eq!(runs[0].answers[0].1, Ok("142".to_string()));
+        assert!(runs[1].answers.iter().all(|(_, answer)| answer.is_err()));
+        assert_eq!(runs[2].answers.iter().map(|(_, a)| a.clone()).collect::<Vec<_>>(),
+                   vec![Ok("142".to_string()), Ok("142".to_string())]);
+    }
+}
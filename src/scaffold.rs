@@ -0,0 +1,110 @@
+//! Start a new day: its module from `src/bin/template.rs`, registered with the
+//! runner, plus empty sample and expected output files.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("bin/template.rs");
+
+/// The template with `DayN` and `dayN` filled in for `day`.
+pub fn render(day: u32) -> String {
+    TEMPLATE.replace("DayN", &format!("Day{day}")).replace("dayN", &format!("day{day}"))
+}
+
+/// Create everything for `day` under `root`, the top of the repo, returning the
+/// paths written. Nothing is written if any of the day's files already exist.
+pub fn new_day(root: &Path, day: u32) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("no day {day}; expected 1 to 25"));
+    }
+    let module = root.join(format!("src/days/day{day}.rs"));
+    let sample = root.join(format!("day{day}-sample.txt"));
+    let expected = root.join(format!("expected/day{day}-sample.txt"));
+    let registry = root.join("src/days/mod.rs");
+    for path in [&module, &sample, &expected] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+    let mods = fs::read_to_string(&registry).map_err(|e| format!("cannot read {}: {e}", registry.display()))?;
+    let mods = register(&mods, day).map_err(|e| format!("{}: {e}", registry.display()))?;
+
+    let write = |path: &Path, text: &str| fs::write(path, text).map_err(|e| format!("cannot write {}: {e}", path.display()));
+    write(&module, &render(day))?;
+    write(&sample, "")?;
+    write(&expected, "# part 1: \n# part 2: \n")?;
+    write(&registry, &mods)?;
+    Ok(vec![module, sample, expected, registry])
+}
+
+/// `mods`, the text of `src/days/mod.rs`, with a `pub mod` and a `DAYS` entry for `day`.
+fn register(mods: &str, day: u32) -> Result<String, String> {
+    let mut lines: Vec<String> = mods.lines().map(String::from).collect();
+    let declared = |l: &str| l.strip_prefix("pub mod day")?.strip_suffix(';')?.parse().ok();
+    let listed = |l: &str| l.trim().strip_prefix("Day::new::<day")?.split_once("::")?.0.parse().ok();
+    insert_sorted(&mut lines, day, declared, format!("pub mod day{day};"))
+        .ok_or_else(|| format!("can't find where to declare day{day}"))?;
+    insert_sorted(&mut lines, day, listed, format!("    Day::new::<day{day}::Day{day}>({day}),"))
+        .ok_or_else(|| format!("can't find where to add day {day} to DAYS"))?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Insert `line` among the run of lines that `number` recognises, keeping their numbers
+/// in order; `None` if no line is recognised or `day` is already there.
+fn insert_sorted<F>(lines: &mut Vec<String>, day: u32, number: F, line: String) -> Option<()>
+    where F: Fn(&str) -> Option<u32>
+{
+    let numbered: Vec<(usize, u32)> = lines.iter().enumerate().filter_map(|(i, l)| Some((i, number(l)?))).collect();
+    if numbered.iter().any(|&(_, n)| n == day) {
+        return None;
+    }
+    let &(last, _) = numbered.last()?;
+    let at = numbered.iter().find(|&&(_, n)| n > day).map_or(last + 1, |&(i, _)| i);
+    lines.insert(at, line);
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODS: &str = "pub mod day1;\npub mod day3;\n\npub const DAYS: &[Day] = &[\n    Day::new::<day1::Day1>(1),\n    Day::new::<day3::Day3>(3),\n];\n";
+
+    #[test]
+    fn test_register() {
+        assert_eq!(register(MODS, 2).unwrap(),
+                   "pub mod day1;\npub mod day2;\npub mod day3;\n\npub const DAYS: &[Day] = &[\n    Day::new::<day1::Day1>(1),\n    Day::new::<day2::Day2>(2),\n    Day::new::<day3::Day3>(3),\n];\n");
+        assert!(register(MODS, 4).unwrap().contains("pub mod day3;\npub mod day4;\n"));
+        assert!(register(MODS, 3).is_err());
+        assert!(register("", 3).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let text = render(11);
+        assert!(text.contains("pub struct Day11;"));
+        assert!(text.contains("day11-sample.txt"));
+        assert!(!text.contains("DayN") && !text.contains("dayN"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("expected")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), MODS).unwrap();
+
+        let written = new_day(&root, 2).unwrap();
+        assert_eq!(written.len(), 4);
+        assert_eq!(fs::read_to_string(root.join("src/days/day2.rs")).unwrap(), render(2));
+        assert_eq!(fs::read_to_string(root.join("day2-sample.txt")).unwrap(), "");
+        assert!(fs::read_to_string(root.join("src/days/mod.rs")).unwrap().contains("pub mod day2;"));
+
+        // A second go must leave the first alone.
+        fs::write(root.join("src/days/day2.rs"), "edited").unwrap();
+        let e = new_day(&root, 2).unwrap_err();
+        assert!(e.ends_with("day2.rs already exists"), "{e}");
+        assert_eq!(fs::read_to_string(root.join("src/days/day2.rs")).unwrap(), "edited");
+        assert!(new_day(&root, 26).is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
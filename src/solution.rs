@@ -0,0 +1,75 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use rand::rngs::StdRng;
+use serde::Deserialize;
+use crate::error::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "u8")]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("no part `{s}`; expected 1 or 2")),
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(n: u8) -> std::result::Result<Self, Self::Error> {
+        n.to_string().parse()
+    }
+}
+
+/// A day's puzzle: parse the input once, then answer either part from it.
+pub trait Solution {
+    type Input;
+    type Output: Display;
+    /// The `size` that gives a generated input about as big as a real one.
+    const SIZE: usize;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Output>;
+    fn part2(input: &Self::Input) -> Result<Self::Output>;
+    /// A random, valid puzzle input; what `size` counts depends on the day.
+    fn generate(rng: &mut StdRng, size: usize) -> String;
+
+    fn solve(input: &str, part: Part) -> Result<String> {
+        let input = Self::parse(input)?;
+        let answer = match part {
+            Part::One => Self::part1(&input)?,
+            Part::Two => Self::part2(&input)?,
+        };
+        Ok(answer.to_string())
+    }
+
+    /// Parse once and answer both parts from that; one part failing doesn't stop the other.
+    fn solve_both(input: &str) -> Result<[Result<String>; 2]> {
+        let input = Self::parse(input)?;
+        Ok([Self::part1(&input).map(|a| a.to_string()), Self::part2(&input).map(|a| a.to_string())])
+    }
+}
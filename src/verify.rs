@@ -0,0 +1,180 @@
+//! Check solutions against the known answers in `answers.toml` and `expected/`.
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use crate::days;
+use crate::input::Input;
+use crate::run;
+use crate::solution::Part;
+
+pub const ANSWERS: &str = "answers.toml";
+/// Expected output for each sample, e.g. `expected/day8-sample2.txt` for `day8-sample2.txt`.
+pub const EXPECTED: &str = "expected";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Answer {
+    pub day: u32,
+    pub part: Part,
+    pub input: PathBuf,
+    pub expected: String,
+    #[serde(default)]
+    pub slow: bool,
+    #[serde(default)]
+    pub known_wrong: bool,
+}
+
+#[derive(Deserialize)]
+struct Answers {
+    answer: Vec<Answer>,
+}
+
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<Answer>, String> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+    let answers: Answers = toml::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(answers.answer)
+}
+
+/// Parse expected output in the form `aoc run` prints it: `part 1: 8` lines and `#` comments.
+pub fn parse_expected(text: &str) -> Result<Vec<(Part, String)>, String> {
+    let mut parts: Vec<(Part, String)> = Vec::new();
+    for l in text.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#')) {
+        let (part, expected) = l.strip_prefix("part ")
+            .and_then(|l| l.split_once(": "))
+            .ok_or_else(|| format!("expected `part N: answer`, got `{l}`"))?;
+        let part: Part = part.parse()?;
+        if parts.iter().any(|(p, _)| *p == part) {
+            return Err(format!("part {part} is listed twice"));
+        }
+        parts.push((part, expected.to_string()));
+    }
+    Ok(parts)
+}
+
+/// The sample answers in `dir`, one file per sample.
+pub fn samples<P: AsRef<Path>>(dir: P) -> Result<Vec<Answer>, String> {
+    let dir = dir.as_ref();
+    let entries = fs::read_dir(dir).map_err(|e| format!("cannot read {}: {e}", dir.display()))?;
+    let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok().map(|e| e.path())).collect();
+    paths.sort();
+    let mut answers = Vec::new();
+    for path in paths {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else { continue };
+        let Some(day) = sample_day(name) else { continue };
+        let text = fs::read_to_string(&path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+        let input = dir.parent().unwrap_or(Path::new("")).join(name);
+        for (part, expected) in parse_expected(&text).map_err(|e| format!("{}: {e}", path.display()))? {
+            answers.push(Answer { day, part, input: input.clone(), expected, slow: false, known_wrong: false });
+        }
+    }
+    Ok(answers)
+}
+
+/// The day of a sample file name like `day10-sample3.txt`.
+pub fn sample_day(name: &str) -> Option<u32> {
+    let (day, rest) = name.strip_prefix("day")?.split_once("-sample")?;
+    rest.strip_suffix(".txt")?;
+    day.parse().ok()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Mismatch { actual: String },
+    Fail(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub answer: Answer,
+    pub outcome: Outcome,
+}
+
+impl Report {
+    /// Whether this outcome is what we expect; known-wrong answers are expected to mismatch.
+    pub fn is_ok(&self) -> bool {
+        match self.outcome {
+            Outcome::Pass => !self.answer.known_wrong,
+            Outcome::Mismatch { .. } => self.answer.known_wrong,
+            Outcome::Fail(_) => false,
+        }
+    }
+}
+
+impl Report {
+    /// A word or two for the outcome, for tables.
+    pub fn status(&self) -> &'static str {
+        match (&self.outcome, self.answer.known_wrong) {
+            (Outcome::Pass, false) => "pass",
+            (Outcome::Pass, true) => "pass (known_wrong)",
+            (Outcome::Mismatch { .. }, false) => "mismatch",
+            (Outcome::Mismatch { .. }, true) => "known mismatch",
+            (Outcome::Fail(_), _) => "fail",
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let a = &self.answer;
+        write!(f, "day {} part {} {}: ", a.day, a.part, a.input.display())?;
+        match (&self.outcome, a.known_wrong) {
+            (Outcome::Pass, false) => write!(f, "pass"),
+            (Outcome::Pass, true) => write!(f, "pass (marked known_wrong; update {ANSWERS})"),
+            (Outcome::Mismatch { actual }, known_wrong) => {
+                write!(f, "{}", if known_wrong { "known mismatch" } else { "mismatch" })?;
+                write!(f, "\n  - {}\n  + {}", a.expected, actual)
+            }
+            (Outcome::Fail(e), _) => {
+                write!(f, "fail")?;
+                e.lines().try_for_each(|l| write!(f, "\n  {l}"))
+            }
+        }
+    }
+}
+
+pub fn check(answer: &Answer) -> Outcome {
+    let Some(day) = days::get(answer.day) else {
+        return Outcome::Fail(format!("no solution for day {}", answer.day));
+    };
+    outcome(answer, run::solve(day, answer.part, &Input::File(answer.input.clone())))
+}
+
+/// How an answer the solver gave, or the error it failed with, compares with the known one.
+pub fn outcome(answer: &Answer, actual: Result<String, String>) -> Outcome {
+    match actual {
+        Ok(actual) if actual == answer.expected => Outcome::Pass,
+        Ok(actual) => Outcome::Mismatch { actual },
+        Err(e) => Outcome::Fail(e),
+    }
+}
+
+pub fn verify(answers: &[Answer], include_slow: bool) -> Vec<Report> {
+    answers.iter()
+        .filter(|a| include_slow || !a.slow)
+        .map(|a| Report { answer: a.clone(), outcome: check(a) })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expected() {
+        let e = parse_expected("# comment\npart 1: 4\n\npart 2: 1\n").unwrap();
+        assert_eq!(e, vec![(Part::One, "4".to_string()), (Part::Two, "1".to_string())]);
+        assert!(parse_expected("4").is_err());
+        assert!(parse_expected("part 3: 4").is_err());
+        assert!(parse_expected("part 1: 4\npart 1: 5").is_err());
+    }
+
+    #[test]
+    fn test_sample_day() {
+        assert_eq!(sample_day("day10-sample3.txt"), Some(10));
+        assert_eq!(sample_day("day1-sample.txt"), Some(1));
+        assert_eq!(sample_day("day1.txt"), None);
+    }
+}
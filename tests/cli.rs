@@ -0,0 +1,27 @@
+//! Run the `aoc` binary itself, for what only the command line does.
+use std::process::{Command, Output};
+
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc")).args(args).output().unwrap()
+}
+
+#[test]
+fn run_day1_with_dictionary() {
+    let french = ["run", "1", "--input", "tests/fixtures/day1-french.txt", "--dictionary", "tests/fixtures/french.txt"];
+    let output = aoc(&[&french[..], &["--part", "2"]].concat());
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "257\n");
+
+    // Part 1 only counts digits, and line 2 has none, but part 2 still gets answered.
+    let output = aoc(&french);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "part 2: 257\n");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("part 1: invalid puzzle input: line 2 has no digits"));
+
+    // Without it, part 2 reads English.
+    let output = aoc(&["run", "1", "--input", "tests/fixtures/day1-french.txt", "--part", "2"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    let output = aoc(&["run", "2", "--dictionary", "tests/fixtures/french.txt"]);
+    assert_eq!(output.status.code(), Some(2));
+}
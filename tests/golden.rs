@@ -0,0 +1,24 @@
+//! A test for each part of each sample with expected output in `expected/`.
+//!
+//! Adding `expected/dayN-sampleK.txt` with `part 1: ...` lines adds tests;
+//! the cases themselves are generated by `build.rs`.
+use std::fs;
+use std::path::Path;
+
+use aoc::solution::Part;
+use aoc::verify::{self, Answer, Outcome, Report, EXPECTED};
+
+fn golden(sample: &str, part: u8) {
+    let part = Part::try_from(part).unwrap();
+    let text = fs::read_to_string(Path::new(EXPECTED).join(sample)).unwrap();
+    let expected = verify::parse_expected(&text).unwrap()
+        .into_iter()
+        .find_map(|(p, e)| (p == part).then_some(e))
+        .unwrap();
+    let day = verify::sample_day(sample).unwrap();
+    let answer = Answer { day, part, input: sample.into(), expected, slow: false, known_wrong: false };
+    let outcome = verify::check(&answer);
+    assert!(outcome == Outcome::Pass, "\n{}", Report { answer, outcome });
+}
+
+include!(concat!(env!("OUT_DIR"), "/golden.rs"));
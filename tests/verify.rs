@@ -0,0 +1,22 @@
+use aoc::verify::{self, ANSWERS};
+
+fn check(include_slow: bool) {
+    let answers = verify::load(ANSWERS).unwrap();
+    let bad: Vec<String> = verify::verify(&answers, include_slow)
+        .into_iter()
+        .filter(|r| !r.is_ok())
+        .map(|r| r.to_string())
+        .collect();
+    assert!(bad.is_empty(), "\n{}", bad.join("\n"));
+}
+
+#[test]
+fn verify_answers() {
+    check(false);
+}
+
+#[test]
+#[ignore = "slow; run with --ignored"]
+fn verify_slow_answers() {
+    check(true);
+}